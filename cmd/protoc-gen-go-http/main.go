@@ -2,28 +2,54 @@ package main
 
 import (
 	"flag"
+	"fmt"
 
 	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/types/descriptorpb"
 	"google.golang.org/protobuf/types/pluginpb"
 
 	"github.com/SebastienMelki/sebuf/internal/httpgen"
+	"github.com/SebastienMelki/sebuf/internal/plugincli"
 )
 
 func main() {
 	var flags flag.FlagSet
 	var generateMock bool
+	var discardUnknownFields bool
 	flags.BoolVar(&generateMock, "generate_mock", false, "generate mock server implementation")
+	flags.Func("unknown_fields", "unknown JSON field policy: reject (default) or ignore", func(value string) error {
+		var err error
+		discardUnknownFields, err = parseUnknownFields(value)
+		return err
+	})
 
 	options := protogen.Options{
 		ParamFunc: flags.Set,
 	}
 
-	options.Run(func(plugin *protogen.Plugin) error {
-		plugin.SupportedFeatures = uint64(pluginpb.CodeGeneratorResponse_FEATURE_PROTO3_OPTIONAL)
+	plugincli.Run("protoc-gen-go-http", options, func(plugin *protogen.Plugin) error {
+		plugin.SupportedFeatures = uint64(pluginpb.CodeGeneratorResponse_FEATURE_PROTO3_OPTIONAL |
+			pluginpb.CodeGeneratorResponse_FEATURE_SUPPORTS_EDITIONS)
+		plugin.SupportedEditionsMinimum = descriptorpb.Edition_EDITION_PROTO2
+		plugin.SupportedEditionsMaximum = descriptorpb.Edition_EDITION_2023
 		opts := httpgen.Options{
-			GenerateMock: generateMock,
+			GenerateMock:         generateMock,
+			DiscardUnknownFields: discardUnknownFields,
 		}
 		gen := httpgen.NewWithOptions(plugin, opts)
 		return gen.Generate()
 	})
 }
+
+// parseUnknownFields maps the unknown_fields parameter to whether unknown JSON keys
+// in request bodies are discarded.
+func parseUnknownFields(value string) (bool, error) {
+	switch value {
+	case "reject":
+		return false, nil
+	case "ignore":
+		return true, nil
+	default:
+		return false, fmt.Errorf("invalid unknown_fields value %q: must be reject or ignore", value)
+	}
+}
@@ -0,0 +1,83 @@
+package main
+
+import (
+	"flag"
+	"fmt"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/pluginpb"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+	"github.com/SebastienMelki/sebuf/internal/plugincli"
+)
+
+func main() {
+	var flags flag.FlagSet
+	format := openapiv3.FormatJSON
+	schemaNaming := openapiv3.SchemaNamingShortDedup
+	flags.Func("format", "output format: json (default) or yaml", func(value string) error {
+		var err error
+		format, err = parseFormat(value)
+		return err
+	})
+	flags.Func("schema_naming", "$defs naming: short_dedup (default), short, package_prefixed or fqn",
+		func(value string) error {
+			var err error
+			schemaNaming, err = openapiv3.ParseSchemaNaming(value)
+			return err
+		})
+
+	options := protogen.Options{
+		ParamFunc: flags.Set,
+	}
+
+	plugincli.Run("protoc-gen-jsonschema", options, func(plugin *protogen.Plugin) error {
+		plugin.SupportedFeatures = uint64(pluginpb.CodeGeneratorResponse_FEATURE_PROTO3_OPTIONAL |
+			pluginpb.CodeGeneratorResponse_FEATURE_SUPPORTS_EDITIONS)
+		plugin.SupportedEditionsMinimum = descriptorpb.Edition_EDITION_PROTO2
+		plugin.SupportedEditionsMaximum = descriptorpb.Edition_EDITION_2023
+		for _, file := range plugin.Files {
+			if !file.Generate {
+				continue
+			}
+			for _, message := range file.Messages {
+				if err := generateSchema(plugin, message, format, schemaNaming); err != nil {
+					return err
+				}
+			}
+		}
+		return nil
+	})
+}
+
+// generateSchema writes the JSON Schema of a top-level message to
+// {package}.{Message}.schema.{json,yaml}. Each message gets a generator of its own, so
+// its $defs hold only the messages it references.
+func generateSchema(
+	plugin *protogen.Plugin,
+	message *protogen.Message,
+	format openapiv3.OutputFormat,
+	schemaNaming openapiv3.SchemaNaming,
+) error {
+	gen := openapiv3.NewGenerator(format)
+	gen.SetSchemaNaming(schemaNaming)
+	data, err := gen.RenderJSONSchema(message, format)
+	if err != nil {
+		return fmt.Errorf("%s: %w", message.Desc.FullName(), err)
+	}
+	filename := fmt.Sprintf("%s.schema.%s", message.Desc.FullName(), format)
+	if _, writeErr := plugin.NewGeneratedFile(filename, "").Write(data); writeErr != nil {
+		return fmt.Errorf("failed to write %s: %w", filename, writeErr)
+	}
+	return nil
+}
+
+// parseFormat maps the format parameter to an output format.
+func parseFormat(value string) (openapiv3.OutputFormat, error) {
+	switch format := openapiv3.OutputFormat(value); format {
+	case openapiv3.FormatJSON, openapiv3.FormatYAML:
+		return format, nil
+	}
+	return "", fmt.Errorf("invalid format %q: must be json or yaml", value)
+}
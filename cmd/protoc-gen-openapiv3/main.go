@@ -1,17 +1,23 @@
 package main
 
 import (
+	"bytes"
+	"encoding/json"
 	"fmt"
-	"io"
 	"os"
+	"path"
+	"path/filepath"
+	"slices"
 	"strings"
 
 	"github.com/pb33f/libopenapi/datamodel/high/base"
 	"google.golang.org/protobuf/compiler/protogen"
-	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
 	"google.golang.org/protobuf/types/pluginpb"
 
+	"github.com/SebastienMelki/sebuf/internal/annotations"
 	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+	"github.com/SebastienMelki/sebuf/internal/plugincli"
 )
 
 // bundleConfig holds origin-level metadata for the bundled OpenAPI document.
@@ -32,39 +38,117 @@ type bundleConfig struct {
 	licenseURL  string
 }
 
+// generatorOptions holds the plugin params that are not bundle_* options.
+type generatorOptions struct {
+	schemaNaming      openapiv3.SchemaNaming
+	operationIDNaming openapiv3.OperationIDNaming
+	// prettyJSON indents JSON output (pretty=true); YAML output is unaffected.
+	prettyJSON bool
+	// enumDetails documents enum value names, descriptions and numeric input (enum_details=true).
+	enumDetails bool
+	// protobufMediaType documents application/x-protobuf bodies (protobuf_media_type=true).
+	protobufMediaType bool
+	// bodyExamples assembles request and response examples from field examples (body_examples=true).
+	bodyExamples bool
+	// skipStreaming leaves streaming methods out of the documents (streaming=skip).
+	skipStreaming bool
+	// splitComponents writes each schema to components/{Name}.yaml (split_components=true).
+	splitComponents bool
+	// hoistInlineSchemas moves repeated inline schemas into components (hoist_inline_schemas=true).
+	hoistInlineSchemas bool
+	// asyncAPI writes an AsyncAPI document next to each document with stream: true RPCs (asyncapi=true).
+	asyncAPI bool
+	// compare is the previous version of the documents, a file or a directory (compare=PATH).
+	compare string
+
+	// title, version, description and serverURLs override the info and servers of
+	// per-service documents. "{service}" in title is replaced with the service name.
+	title       string
+	version     string
+	description string
+	serverURLs  []string
+}
+
+// knownParameters are the plugin parameters protoc-gen-openapiv3 reads. protogen's own
+// parameters (paths, module, annotate_code and M mappings) are accepted as well.
+//
+//nolint:gochecknoglobals // read-only list of parameter names
+var knownParameters = []string{
+	"asyncapi", "body_examples", "bundle", "bundle_contact_email", "bundle_contact_name",
+	"bundle_contact_url", "bundle_description", "bundle_license_name", "bundle_license_url",
+	"bundle_only", "bundle_output", "bundle_server", "bundle_title", "bundle_version", "compare",
+	"description", "enum_details", "format", "hoist_inline_schemas", "operation_id_naming", "pretty",
+	"protobuf_media_type", "schema_naming", "server_url", "split_components", "streaming", "title",
+	"version",
+}
+
+// pluginName prefixes the messages the plugin prints itself.
+const pluginName = "protoc-gen-openapiv3"
+
+// main exits nonzero only when the request cannot be read or the response cannot be
+// written, or when run by hand without a request. Generation errors are reported in
+// CodeGeneratorResponse.error, which protoc prints as "--openapiv3_out: {error}".
 func main() {
-	req := readRequest()
-	params := parseParameters(req.GetParameter())
-	format := parseFormat(params)
-	bundle := parseBundleConfig(params)
-	plugin := createPlugin(req)
-	generateOpenAPIFiles(plugin, format, bundle)
-	writeResponse(plugin)
+	plugincli.Run(pluginName, protogen.Options{}, func(plugin *protogen.Plugin) error {
+		plugin.SupportedFeatures = uint64(pluginpb.CodeGeneratorResponse_FEATURE_PROTO3_OPTIONAL |
+			pluginpb.CodeGeneratorResponse_FEATURE_SUPPORTS_EDITIONS)
+		plugin.SupportedEditionsMinimum = descriptorpb.Edition_EDITION_PROTO2
+		plugin.SupportedEditionsMaximum = descriptorpb.Edition_EDITION_2023
+		return run(plugin, plugin.Request.GetParameter())
+	})
 }
 
-func readRequest() *pluginpb.CodeGeneratorRequest {
-	input, err := io.ReadAll(os.Stdin)
+// run parses the plugin parameters and writes the requested documents to plugin.
+func run(plugin *protogen.Plugin, parameter string) error {
+	params := parseParameters(parameter)
+	if err := checkParameters(params); err != nil {
+		return err
+	}
+	opts, err := parseGeneratorOptions(params)
+	if err != nil {
+		return err
+	}
+	formats, err := parseFormats(params)
 	if err != nil {
-		panic(err)
+		return err
 	}
+	bundle := parseBundleConfig(params)
+	if err = checkBundleOutput(bundle.output); err != nil {
+		return err
+	}
+	return generateOpenAPIFiles(plugin, formats, bundle, opts)
+}
 
-	var req pluginpb.CodeGeneratorRequest
-	if unmarshalErr := proto.Unmarshal(input, &req); unmarshalErr != nil {
-		panic(unmarshalErr)
+// checkBundleOutput rejects a bundle_output that leaves the output directory. protoc and
+// buf resolve generated file names against the plugin's out directory, and buf rejects
+// absolute names and names that climb out of it.
+func checkBundleOutput(output string) error {
+	if output == "" {
+		return nil
+	}
+	clean := path.Clean(output)
+	if path.IsAbs(clean) || clean == ".." || strings.HasPrefix(clean, "../") {
+		return fmt.Errorf("bundle_output %q must be a relative path inside the output directory", output)
 	}
-	return &req
+	return nil
 }
 
-func parseFormat(params map[string][]string) openapiv3.OutputFormat {
-	if vs, ok := params["format"]; ok && len(vs) > 0 {
-		switch vs[0] {
-		case "json":
-			return openapiv3.FormatJSON
-		case "yaml", "yml":
-			return openapiv3.FormatYAML
-		}
+// parseFormats returns the output formats requested by the format param: yaml (the
+// default), json, or both. With both, each document is written once per format.
+func parseFormats(params map[string][]string) ([]openapiv3.OutputFormat, error) {
+	vs, ok := params["format"]
+	if !ok || len(vs) == 0 {
+		return []openapiv3.OutputFormat{openapiv3.FormatYAML}, nil
+	}
+	switch vs[0] {
+	case "json":
+		return []openapiv3.OutputFormat{openapiv3.FormatJSON}, nil
+	case "yaml", "yml":
+		return []openapiv3.OutputFormat{openapiv3.FormatYAML}, nil
+	case "both":
+		return []openapiv3.OutputFormat{openapiv3.FormatYAML, openapiv3.FormatJSON}, nil
 	}
-	return openapiv3.FormatYAML
+	return nil, fmt.Errorf("invalid format %q: must be yaml, yml, json or both", vs[0])
 }
 
 // parseBundleConfig extracts bundle_* plugin params. Repeated keys (notably
@@ -99,83 +183,343 @@ func parseBundleConfig(params map[string][]string) bundleConfig {
 	return cfg
 }
 
-func createPlugin(req *pluginpb.CodeGeneratorRequest) *protogen.Plugin {
-	opts := protogen.Options{}
-	plugin, err := opts.New(req)
-	if err != nil {
-		panic(err)
+// parseGeneratorOptions extracts the plugin params that are not bundle_* options.
+func parseGeneratorOptions(params map[string][]string) (generatorOptions, error) {
+	first := func(key string) string {
+		if vs, ok := params[key]; ok && len(vs) > 0 {
+			return vs[0]
+		}
+		return ""
+	}
+
+	opts := generatorOptions{
+		prettyJSON:        first("pretty") == "true" || first("pretty") == "1",
+		enumDetails:       first("enum_details") == "true" || first("enum_details") == "1",
+		splitComponents:   first("split_components") == "true" || first("split_components") == "1",
+		protobufMediaType: first("protobuf_media_type") == "true" || first("protobuf_media_type") == "1",
+		bodyExamples:      first("body_examples") == "true" || first("body_examples") == "1",
+		asyncAPI:          first("asyncapi") == "true" || first("asyncapi") == "1",
+		title:             first("title"),
+		version:           first("version"),
+		description:       first("description"),
+		serverURLs:        params["server_url"],
+		compare:           first("compare"),
+	}
+	if v := first("hoist_inline_schemas"); v == "true" || v == "1" {
+		opts.hoistInlineSchemas = true
+	}
+	switch v := first("streaming"); v {
+	case "", "sse":
+	case "skip":
+		opts.skipStreaming = true
+	default:
+		return opts, fmt.Errorf("unknown streaming option %q (want sse or skip)", v)
+	}
+	if v := first("schema_naming"); v != "" {
+		naming, err := openapiv3.ParseSchemaNaming(v)
+		if err != nil {
+			return opts, err
+		}
+		opts.schemaNaming = naming
+	}
+	if v := first("operation_id_naming"); v != "" {
+		naming, err := openapiv3.ParseOperationIDNaming(v)
+		if err != nil {
+			return opts, err
+		}
+		opts.operationIDNaming = naming
 	}
-	return plugin
+	return opts, nil
 }
 
-func generateOpenAPIFiles(plugin *protogen.Plugin, format openapiv3.OutputFormat, bundle bundleConfig) {
+// apply configures a freshly created generator; it must run before any processing.
+func (o generatorOptions) apply(g *openapiv3.Generator) {
+	if o.schemaNaming != "" {
+		g.SetSchemaNaming(o.schemaNaming)
+	}
+	g.SetOperationIDNaming(o.operationIDNaming)
+	g.SetEnumDetails(o.enumDetails)
+	g.SetSkipStreaming(o.skipStreaming)
+	g.SetProtobufMediaType(o.protobufMediaType)
+	g.SetBodyExamples(o.bodyExamples)
+	g.SetHoistInlineSchemas(o.hoistInlineSchemas)
+}
+
+func generateOpenAPIFiles(
+	plugin *protogen.Plugin,
+	formats []openapiv3.OutputFormat,
+	bundle bundleConfig,
+	opts generatorOptions,
+) error {
+	if err := validateMethodAnnotations(plugin); err != nil {
+		return err
+	}
+	out := &outputWriter{
+		plugin:     plugin,
+		prettyJSON: opts.prettyJSON,
+		documents:  make(map[string][]byte),
+		components: make(map[string][]byte),
+		written:    make(map[string]bool),
+	}
+
 	// Per-service output (default behaviour; suppressed when bundle_only=true).
 	if !bundle.enabled || !bundle.onlyBundle {
 		for _, file := range plugin.Files {
 			if !file.Generate {
 				continue
 			}
-			processFileServices(plugin, file, format)
+			if err := processFileServices(plugin, file, formats, opts, out); err != nil {
+				return fmt.Errorf("%s: %w", file.Desc.Path(), err)
+			}
 		}
 	}
 
 	if bundle.enabled {
-		generateBundleFile(plugin, format, bundle)
+		if err := generateBundleFile(plugin, formats, bundle, opts, out); err != nil {
+			return err
+		}
 	}
+
+	if opts.compare != "" {
+		return reportBreakingChanges(opts.compare, out.documents)
+	}
+	return nil
 }
 
-func processFileServices(plugin *protogen.Plugin, file *protogen.File, format openapiv3.OutputFormat) {
+// validateMethodAnnotations fails generation when an HttpConfig.responses entry has an
+// invalid status code or names a message that cannot be resolved, or when an
+// openapi_extensions entry has a bad name or a value that is not JSON.
+func validateMethodAnnotations(plugin *protogen.Plugin) error {
+	for _, file := range plugin.Files {
+		if !file.Generate {
+			continue
+		}
+		for _, service := range file.Services {
+			for _, method := range service.Methods {
+				if _, err := annotations.GetMethodResponses(method); err != nil {
+					return fmt.Errorf("%s: %s: %w", file.Desc.Path(), method.Desc.FullName(), err)
+				}
+				if _, err := annotations.GetOpenAPIExtensions(service, method); err != nil {
+					return fmt.Errorf("%s: %s: %w", file.Desc.Path(), method.Desc.FullName(), err)
+				}
+			}
+		}
+	}
+	return nil
+}
+
+func processFileServices(
+	plugin *protogen.Plugin,
+	file *protogen.File,
+	formats []openapiv3.OutputFormat,
+	opts generatorOptions,
+	out *outputWriter,
+) error {
 	for _, service := range file.Services {
-		generator := createServiceGenerator(file, service, format)
-		output := renderService(generator)
-		writeServiceFile(plugin, service, output, format)
+		generator := createServiceGenerator(plugin.Files, service, formats[0], opts)
+		for _, format := range formats {
+			output, files, err := renderService(generator, format, opts, string(service.Desc.Name()))
+			if err != nil {
+				return err
+			}
+			if err = out.writeDocument(serviceFilename(service, format), output); err != nil {
+				return err
+			}
+			if err = out.writeComponents("", files); err != nil {
+				return err
+			}
+			if opts.asyncAPI {
+				filename := fmt.Sprintf("%s.asyncapi.%s", service.Desc.Name(), extension(format))
+				if err = out.writeAsyncAPI(filename, generator, format); err != nil {
+					return err
+				}
+			}
+		}
 	}
+	return nil
 }
 
 func createServiceGenerator(
-	_ *protogen.File,
+	files []*protogen.File,
 	service *protogen.Service,
 	format openapiv3.OutputFormat,
+	opts generatorOptions,
 ) *openapiv3.Generator {
 	generator := openapiv3.NewGenerator(format)
+	opts.apply(generator)
+	generator.RegisterFiles(files)
+
+	// A service_config host, applied by ProcessService, takes precedence over server_url.
+	if len(opts.serverURLs) > 0 {
+		generator.SetServers(opts.serverURLs)
+	}
 
 	// Collect all messages referenced by this service, including those from other files
 	generator.CollectReferencedMessages(service)
 
 	generator.ProcessService(service)
+
+	// ProcessService derives the title from the service name, so overrides come after it.
+	title := strings.ReplaceAll(opts.title, "{service}", string(service.Desc.Name()))
+	generator.SetInfo(title, opts.version, opts.description, nil, nil)
 	return generator
 }
 
-func renderService(generator *openapiv3.Generator) []byte {
-	output, renderErr := generator.Render()
+// renderService renders the document in format and refuses to return a document that
+// fails openapiv3.CheckDocument, so broken references never reach downstream tools. With
+// split_components=true it also returns the schema files the document references.
+func renderService(
+	generator *openapiv3.Generator,
+	format openapiv3.OutputFormat,
+	opts generatorOptions,
+	name string,
+) ([]byte, []openapiv3.ComponentFile, error) {
+	var output []byte
+	var components []openapiv3.ComponentFile
+	var renderErr error
+	if opts.splitComponents {
+		output, components, renderErr = generator.RenderSplit(format)
+	} else {
+		output, renderErr = generator.RenderAs(format)
+	}
 	if renderErr != nil {
-		panic(renderErr)
+		return nil, nil, fmt.Errorf("%s: %w", name, renderErr)
+	}
+	problems, checkErr := openapiv3.CheckDocument(output)
+	if checkErr != nil {
+		return nil, nil, fmt.Errorf("%s: %w", name, checkErr)
 	}
-	return output
+	if len(problems) > 0 {
+		return nil, nil, fmt.Errorf("%s: generated OpenAPI document is invalid:\n  %s",
+			name, strings.Join(problems, "\n  "))
+	}
+	if format == openapiv3.FormatJSON && opts.prettyJSON {
+		var indentErr error
+		if output, indentErr = indentJSON(output); indentErr != nil {
+			return nil, nil, fmt.Errorf("%s: %w", name, indentErr)
+		}
+		for i := range components {
+			if components[i].Content, indentErr = indentJSON(components[i].Content); indentErr != nil {
+				return nil, nil, fmt.Errorf("%s: %w", components[i].Path, indentErr)
+			}
+		}
+	}
+	return output, components, nil
 }
 
-func writeServiceFile(
-	plugin *protogen.Plugin,
-	service *protogen.Service,
-	output []byte,
+// indentJSON pretty-prints a JSON document with two-space indentation.
+func indentJSON(data []byte) ([]byte, error) {
+	var indented bytes.Buffer
+	if indentErr := json.Indent(&indented, data, "", "  "); indentErr != nil {
+		return nil, fmt.Errorf("failed to indent JSON: %w", indentErr)
+	}
+	indented.WriteByte('\n')
+	return indented.Bytes(), nil
+}
+
+// outputWriter writes the generated documents and their split_components schema files.
+// Documents generated in the same run share the components directory, so a schema file
+// another document already wrote is skipped, and two documents that need different
+// schemas under one name fail generation.
+type outputWriter struct {
+	plugin     *protogen.Plugin
+	prettyJSON bool
+	// documents holds each written document by filename, for the compare option.
+	documents  map[string][]byte
+	components map[string][]byte
+	// written holds every filename written so far, to catch two outputs sharing a name.
+	written map[string]bool
+}
+
+// writeDocument writes a rendered document to filename.
+func (w *outputWriter) writeDocument(filename string, output []byte) error {
+	w.documents[filename] = output
+	return w.writeFile(filename, output)
+}
+
+// writeAsyncAPI writes the AsyncAPI document of generator's stream: true RPCs to filename,
+// unless it has none.
+func (w *outputWriter) writeAsyncAPI(
+	filename string,
+	generator *openapiv3.Generator,
 	format openapiv3.OutputFormat,
-) {
-	ext := "yaml"
-	if format == openapiv3.FormatJSON {
-		ext = "json"
+) error {
+	if !generator.HasEventChannels() {
+		return nil
+	}
+	output, err := generator.RenderAsyncAPI(format)
+	if err != nil {
+		return fmt.Errorf("%s: %w", filename, err)
 	}
-	filename := fmt.Sprintf("%s.openapi.%s", service.Desc.Name(), ext)
+	if format == openapiv3.FormatJSON && w.prettyJSON {
+		if output, err = indentJSON(output); err != nil {
+			return fmt.Errorf("%s: %w", filename, err)
+		}
+	}
+	return w.writeFile(filename, output)
+}
+
+// writeFile writes content to filename. Two services with the same name in different
+// packages would both write {Service}.openapi.yaml, and protoc would keep only one, so
+// writing a filename twice fails generation instead.
+func (w *outputWriter) writeFile(filename string, content []byte) error {
+	if w.written[filename] {
+		return fmt.Errorf("%s: generated twice, most likely by services with the same name in different "+
+			"packages; rename one of them, or use bundle=true with bundle_only=true", filename)
+	}
+	w.written[filename] = true
+	generatedFile := w.plugin.NewGeneratedFile(filename, "")
+	if _, writeErr := generatedFile.Write(content); writeErr != nil {
+		return fmt.Errorf("%s: %w", filename, writeErr)
+	}
+	return nil
+}
 
-	generatedFile := plugin.NewGeneratedFile(filename, "")
-	if _, writeErr := generatedFile.Write(output); writeErr != nil {
-		panic(writeErr)
+// writeComponents writes files relative to dir, the directory of the document
+// referencing them.
+func (w *outputWriter) writeComponents(dir string, files []openapiv3.ComponentFile) error {
+	for _, file := range files {
+		filename := path.Join(dir, file.Path)
+		if previous, ok := w.components[filename]; ok {
+			if !bytes.Equal(previous, file.Content) {
+				return fmt.Errorf("%s: two documents define different schemas with this name; "+
+					"use schema_naming to give them distinct names", filename)
+			}
+			continue
+		}
+		w.components[filename] = file.Content
+		if err := w.writeFile(filename, file.Content); err != nil {
+			return err
+		}
 	}
+	return nil
+}
+
+// serviceFilename returns {Service}.openapi.{yaml,json}.
+func serviceFilename(service *protogen.Service, format openapiv3.OutputFormat) string {
+	return fmt.Sprintf("%s.openapi.%s", service.Desc.Name(), extension(format))
+}
+
+// extension returns the file extension of format.
+func extension(format openapiv3.OutputFormat) string {
+	if format == openapiv3.FormatJSON {
+		return "json"
+	}
+	return "yaml"
 }
 
 // generateBundleFile collects every service across every generated proto file into a
 // single OpenAPI document with proto-package-qualified schema names.
-func generateBundleFile(plugin *protogen.Plugin, format openapiv3.OutputFormat, cfg bundleConfig) {
-	generator := openapiv3.NewBundleGenerator(format)
+func generateBundleFile(
+	plugin *protogen.Plugin,
+	formats []openapiv3.OutputFormat,
+	cfg bundleConfig,
+	opts generatorOptions,
+	out *outputWriter,
+) error {
+	generator := openapiv3.NewBundleGenerator(formats[0])
+	opts.apply(generator)
+	generator.RegisterFiles(plugin.Files)
 	applyBundleMetadata(generator, cfg)
 
 	serviceCount := 0
@@ -192,11 +536,34 @@ func generateBundleFile(plugin *protogen.Plugin, format openapiv3.OutputFormat,
 
 	// No services in the protoc invocation — skip writing an empty bundle.
 	if serviceCount == 0 {
-		return
+		return nil
+	}
+
+	// Merging must not silently drop an operation or ship duplicate operationIds.
+	if conflicts := generator.Conflicts(); len(conflicts) > 0 {
+		return fmt.Errorf("bundle has conflicting operations:\n  %s", strings.Join(conflicts, "\n  "))
 	}
 
-	output := renderService(generator)
-	writeBundleFile(plugin, output, format, cfg)
+	for _, format := range formats {
+		output, files, err := renderService(generator, format, opts, "bundle")
+		if err != nil {
+			return err
+		}
+		filename := bundleFilename(format, cfg, len(formats) > 1)
+		if err = out.writeDocument(filename, output); err != nil {
+			return err
+		}
+		if err = out.writeComponents(path.Dir(filename), files); err != nil {
+			return err
+		}
+		if opts.asyncAPI {
+			asyncAPIFilename := path.Join(path.Dir(filename), "asyncapi."+extension(format))
+			if err = out.writeAsyncAPI(asyncAPIFilename, generator, format); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
 }
 
 func applyBundleMetadata(g *openapiv3.Generator, cfg bundleConfig) {
@@ -219,38 +586,92 @@ func applyBundleMetadata(g *openapiv3.Generator, cfg bundleConfig) {
 	g.SetServers(cfg.servers)
 }
 
-func writeBundleFile(plugin *protogen.Plugin, output []byte, format openapiv3.OutputFormat, cfg bundleConfig) {
+// bundleFilename returns bundle_output (or openapi.{yaml,json}). When several formats are
+// written, the extension of bundle_output is replaced per format so the files do not
+// collide.
+func bundleFilename(format openapiv3.OutputFormat, cfg bundleConfig, multiFormat bool) string {
+	ext := extension(format)
 	filename := cfg.output
-	if filename == "" {
-		if format == openapiv3.FormatJSON {
-			filename = "openapi.json"
-		} else {
-			filename = "openapi.yaml"
-		}
-	}
-	generatedFile := plugin.NewGeneratedFile(filename, "")
-	if _, writeErr := generatedFile.Write(output); writeErr != nil {
-		panic(writeErr)
+	switch {
+	case filename == "":
+		filename = "openapi." + ext
+	case multiFormat:
+		filename = strings.TrimSuffix(filename, path.Ext(filename)) + "." + ext
 	}
+	return filename
 }
 
-func writeResponse(plugin *protogen.Plugin) {
-	resp := plugin.Response()
-	resp.SupportedFeatures = proto.Uint64(uint64(pluginpb.CodeGeneratorResponse_FEATURE_PROTO3_OPTIONAL))
+// reportBreakingChanges compares the documents written in this run with their previous
+// version and fails generation when any change would break existing clients. previous
+// is either a directory holding the earlier output, where each document is compared
+// with the file of the same name (documents without one are new and skipped), or a
+// single file, compared with the one document that has its extension.
+func reportBreakingChanges(previous string, documents map[string][]byte) error {
+	info, err := os.Stat(previous)
+	if err != nil {
+		return fmt.Errorf("compare: %w", err)
+	}
+
+	filenames := make([]string, 0, len(documents))
+	for filename := range documents {
+		filenames = append(filenames, filename)
+	}
+	slices.Sort(filenames)
 
-	respOutput, err := proto.Marshal(resp)
+	pairs, err := previousDocuments(previous, info.IsDir(), filenames)
 	if err != nil {
-		panic(err)
+		return err
 	}
+	var report []string
+	for _, filename := range filenames {
+		oldPath, ok := pairs[filename]
+		if !ok {
+			continue
+		}
+		old, readErr := os.ReadFile(oldPath)
+		if readErr != nil {
+			return fmt.Errorf("compare: %w", readErr)
+		}
+		changes, compareErr := openapiv3.CompareDocuments(old, documents[filename])
+		if compareErr != nil {
+			return fmt.Errorf("compare %s: %w", oldPath, compareErr)
+		}
+		for _, change := range changes {
+			report = append(report, filename+": "+change)
+		}
+	}
+	if len(report) > 0 {
+		return fmt.Errorf("breaking changes against %s:\n  %s", previous, strings.Join(report, "\n  "))
+	}
+	return nil
+}
 
-	if _, writeErr := os.Stdout.Write(respOutput); writeErr != nil {
-		panic(writeErr)
+// previousDocuments maps each generated filename to the previous version it is compared
+// with; see reportBreakingChanges.
+func previousDocuments(previous string, isDir bool, filenames []string) (map[string]string, error) {
+	pairs := make(map[string]string)
+	for _, filename := range filenames {
+		switch {
+		case isDir:
+			oldPath := filepath.Join(previous, filepath.FromSlash(filename))
+			if _, statErr := os.Stat(oldPath); statErr == nil {
+				pairs[filename] = oldPath
+			}
+		case path.Ext(filename) == filepath.Ext(previous):
+			pairs[filename] = previous
+		}
 	}
+	if !isDir && len(pairs) != 1 {
+		return nil, fmt.Errorf("compare: %s is a single document but %d generated documents match it; "+
+			"pass the directory holding the previous output instead", previous, len(pairs))
+	}
+	return pairs, nil
 }
 
 // parseParameters parses protoc plugin parameters in the format
 // "key=value,key2=value2". Repeated keys (e.g. bundle_server) collect into a slice
-// in insertion order; the first value is used for scalar options.
+// in insertion order; the first value is used for scalar options. A key without "="
+// gets an empty value, so checkParameters still sees it.
 //
 // Commas inside values can be escaped with a backslash ("\,") — required for
 // bundle_description and similar prose fields. The escape sequence is unescaped
@@ -262,18 +683,37 @@ func parseParameters(parameter string) map[string][]string {
 	}
 
 	for _, pair := range splitUnescapedComma(parameter) {
-		const splitLimit = 2
-		kv := strings.SplitN(pair, "=", splitLimit)
-		if len(kv) != splitLimit {
+		key, value, _ := strings.Cut(pair, "=")
+		key = strings.TrimSpace(key)
+		if key == "" {
 			continue
 		}
-		key := strings.TrimSpace(kv[0])
-		value := strings.ReplaceAll(strings.TrimSpace(kv[1]), `\,`, ",")
+		value = strings.ReplaceAll(strings.TrimSpace(value), `\,`, ",")
 		params[key] = append(params[key], value)
 	}
 	return params
 }
 
+// checkParameters rejects parameters the plugin does not know, so a misspelled option
+// fails generation instead of being silently ignored.
+func checkParameters(params map[string][]string) error {
+	var unknown []string
+	for key := range params {
+		switch {
+		case slices.Contains(knownParameters, key):
+		case key == "paths" || key == "module" || key == "annotate_code" || strings.HasPrefix(key, "M"):
+		default:
+			unknown = append(unknown, key)
+		}
+	}
+	if len(unknown) == 0 {
+		return nil
+	}
+	slices.Sort(unknown)
+	return fmt.Errorf("unknown parameter(s) %s; known parameters are %s",
+		strings.Join(unknown, ", "), strings.Join(knownParameters, ", "))
+}
+
 // splitUnescapedComma splits on commas but treats "\," as a literal comma.
 func splitUnescapedComma(s string) []string {
 	var out []string
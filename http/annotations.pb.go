@@ -382,7 +382,23 @@ type HttpConfig struct {
 	// When true, this method uses Server-Sent Events (SSE) for streaming responses.
 	// The server sends events with Content-Type: text/event-stream.
 	// Each event is the response message serialized as JSON in the SSE data field.
-	Stream        bool `protobuf:"varint,3,opt,name=stream,proto3" json:"stream,omitempty"`
+	Stream bool `protobuf:"varint,3,opt,name=stream,proto3" json:"stream,omitempty"`
+	// Maximum number of requests to this method handled concurrently. Requests beyond
+	// the limit are rejected with 503 Service Unavailable. Zero means unlimited.
+	MaxConcurrentRequests uint32 `protobuf:"varint,4,opt,name=max_concurrent_requests,json=maxConcurrentRequests,proto3" json:"max_concurrent_requests,omitempty"`
+	// Additional responses this method can return (e.g. 404 with a NotFoundDetails body),
+	// documented on the generated OpenAPI operation next to the default responses.
+	Responses []*ResponseConfig `protobuf:"bytes,5,rep,name=responses,proto3" json:"responses,omitempty"`
+	// Vendor extensions (x-*) copied onto the generated OpenAPI operation. They override
+	// service-level extensions with the same name.
+	OpenapiExtensions []*OpenAPIExtension `protobuf:"bytes,6,rep,name=openapi_extensions,json=openapiExtensions,proto3" json:"openapi_extensions,omitempty"`
+	// Tags of the generated OpenAPI operation, replacing the service's tags. Tags declared
+	// in ServiceConfig.tags keep their description and external docs.
+	Tags []string `protobuf:"bytes,7,rep,name=tags,proto3" json:"tags,omitempty"`
+	// When true, this method describes a webhook: a request the server sends to
+	// subscribers rather than an endpoint it serves. protoc-gen-openapiv3 lists it under
+	// the document's webhooks, keyed by the RPC name, instead of under paths.
+	Webhook       bool `protobuf:"varint,8,opt,name=webhook,proto3" json:"webhook,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -438,11 +454,57 @@ func (x *HttpConfig) GetStream() bool {
 	return false
 }
 
+func (x *HttpConfig) GetMaxConcurrentRequests() uint32 {
+	if x != nil {
+		return x.MaxConcurrentRequests
+	}
+	return 0
+}
+
+func (x *HttpConfig) GetResponses() []*ResponseConfig {
+	if x != nil {
+		return x.Responses
+	}
+	return nil
+}
+
+func (x *HttpConfig) GetOpenapiExtensions() []*OpenAPIExtension {
+	if x != nil {
+		return x.OpenapiExtensions
+	}
+	return nil
+}
+
+func (x *HttpConfig) GetTags() []string {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
+func (x *HttpConfig) GetWebhook() bool {
+	if x != nil {
+		return x.Webhook
+	}
+	return false
+}
+
 // ServiceConfig defines HTTP-specific configuration for an entire service
 type ServiceConfig struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
 	// Base path prefix for all methods in this service
-	BasePath      string `protobuf:"bytes,1,opt,name=base_path,json=basePath,proto3" json:"base_path,omitempty"`
+	BasePath string `protobuf:"bytes,1,opt,name=base_path,json=basePath,proto3" json:"base_path,omitempty"`
+	// Host the service is served from, including scheme (e.g. "https://api.example.com").
+	// Emitted as the servers entry for this service in the generated OpenAPI document.
+	Host string `protobuf:"bytes,2,opt,name=host,proto3" json:"host,omitempty"`
+	// API versions this service accepts in the X-API-Version request header. The first
+	// entry is used when the header is absent; any other value is rejected with 406.
+	ApiVersions []string `protobuf:"bytes,3,rep,name=api_versions,json=apiVersions,proto3" json:"api_versions,omitempty"`
+	// Vendor extensions (x-*) copied onto every OpenAPI operation of this service.
+	OpenapiExtensions []*OpenAPIExtension `protobuf:"bytes,4,rep,name=openapi_extensions,json=openapiExtensions,proto3" json:"openapi_extensions,omitempty"`
+	// Tags of this service's OpenAPI operations, replacing the default tag (the service
+	// name). Each is also listed in the document's top-level tags.
+	Tags          []*OpenAPITag `protobuf:"bytes,5,rep,name=tags,proto3" json:"tags,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -484,6 +546,34 @@ func (x *ServiceConfig) GetBasePath() string {
 	return ""
 }
 
+func (x *ServiceConfig) GetHost() string {
+	if x != nil {
+		return x.Host
+	}
+	return ""
+}
+
+func (x *ServiceConfig) GetApiVersions() []string {
+	if x != nil {
+		return x.ApiVersions
+	}
+	return nil
+}
+
+func (x *ServiceConfig) GetOpenapiExtensions() []*OpenAPIExtension {
+	if x != nil {
+		return x.OpenapiExtensions
+	}
+	return nil
+}
+
+func (x *ServiceConfig) GetTags() []*OpenAPITag {
+	if x != nil {
+		return x.Tags
+	}
+	return nil
+}
+
 // FieldExamples defines example values for a field
 type FieldExamples struct {
 	state protoimpl.MessageState `protogen:"open.v1"`
@@ -645,6 +735,192 @@ func (x *OneofConfig) GetFlatten() bool {
 	return false
 }
 
+// ResponseConfig documents an additional response of an RPC method.
+// Listed in HttpConfig.responses.
+type ResponseConfig struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// HTTP status code of the response (e.g. 404).
+	Status uint32 `protobuf:"varint,1,opt,name=status,proto3" json:"status,omitempty"`
+	// When the response is returned.
+	Description string `protobuf:"bytes,2,opt,name=description,proto3" json:"description,omitempty"`
+	// Fully-qualified name of the message sent as the response body
+	// (e.g. "acme.users.v1.NotFoundDetails"). Empty for a response without a body.
+	Message       string `protobuf:"bytes,3,opt,name=message,proto3" json:"message,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ResponseConfig) Reset() {
+	*x = ResponseConfig{}
+	mi := &file_sebuf_http_annotations_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ResponseConfig) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ResponseConfig) ProtoMessage() {}
+
+func (x *ResponseConfig) ProtoReflect() protoreflect.Message {
+	mi := &file_sebuf_http_annotations_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ResponseConfig.ProtoReflect.Descriptor instead.
+func (*ResponseConfig) Descriptor() ([]byte, []int) {
+	return file_sebuf_http_annotations_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *ResponseConfig) GetStatus() uint32 {
+	if x != nil {
+		return x.Status
+	}
+	return 0
+}
+
+func (x *ResponseConfig) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *ResponseConfig) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+// OpenAPIExtension is a vendor extension copied verbatim into the generated OpenAPI
+// document, e.g. gateway metadata such as x-amazon-apigateway-integration.
+type OpenAPIExtension struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Extension name. Must start with "x-".
+	Name string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	// Extension value as JSON (e.g. "{\"type\": \"aws_proxy\"}" or "\"internal\"").
+	Value         string `protobuf:"bytes,2,opt,name=value,proto3" json:"value,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *OpenAPIExtension) Reset() {
+	*x = OpenAPIExtension{}
+	mi := &file_sebuf_http_annotations_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OpenAPIExtension) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OpenAPIExtension) ProtoMessage() {}
+
+func (x *OpenAPIExtension) ProtoReflect() protoreflect.Message {
+	mi := &file_sebuf_http_annotations_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OpenAPIExtension.ProtoReflect.Descriptor instead.
+func (*OpenAPIExtension) Descriptor() ([]byte, []int) {
+	return file_sebuf_http_annotations_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *OpenAPIExtension) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *OpenAPIExtension) GetValue() string {
+	if x != nil {
+		return x.Value
+	}
+	return ""
+}
+
+// OpenAPITag is a tag used to group operations in the generated OpenAPI document.
+type OpenAPITag struct {
+	state protoimpl.MessageState `protogen:"open.v1"`
+	// Tag name.
+	Name string `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	// Description of the tag, shown with the operation group in documentation tools.
+	Description string `protobuf:"bytes,2,opt,name=description,proto3" json:"description,omitempty"`
+	// URL of external documentation for the tag.
+	ExternalDocsUrl string `protobuf:"bytes,3,opt,name=external_docs_url,json=externalDocsUrl,proto3" json:"external_docs_url,omitempty"`
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *OpenAPITag) Reset() {
+	*x = OpenAPITag{}
+	mi := &file_sebuf_http_annotations_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *OpenAPITag) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*OpenAPITag) ProtoMessage() {}
+
+func (x *OpenAPITag) ProtoReflect() protoreflect.Message {
+	mi := &file_sebuf_http_annotations_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use OpenAPITag.ProtoReflect.Descriptor instead.
+func (*OpenAPITag) Descriptor() ([]byte, []int) {
+	return file_sebuf_http_annotations_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *OpenAPITag) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *OpenAPITag) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *OpenAPITag) GetExternalDocsUrl() string {
+	if x != nil {
+		return x.ExternalDocsUrl
+	}
+	return ""
+}
+
 var file_sebuf_http_annotations_proto_extTypes = []protoimpl.ExtensionInfo{
 	{
 		ExtendedType:  (*descriptorpb.MethodOptions)(nil),
@@ -887,14 +1163,23 @@ var File_sebuf_http_annotations_proto protoreflect.FileDescriptor
 const file_sebuf_http_annotations_proto_rawDesc = "" +
 	"\n" +
 	"\x1csebuf/http/annotations.proto\x12\n" +
-	"sebuf.http\x1a google/protobuf/descriptor.proto\"h\n" +
+	"sebuf.http\x1a google/protobuf/descriptor.proto\"\xd5\x02\n" +
 	"\n" +
 	"HttpConfig\x12\x12\n" +
 	"\x04path\x18\x01 \x01(\tR\x04path\x12.\n" +
 	"\x06method\x18\x02 \x01(\x0e2\x16.sebuf.http.HttpMethodR\x06method\x12\x16\n" +
-	"\x06stream\x18\x03 \x01(\bR\x06stream\",\n" +
+	"\x06stream\x18\x03 \x01(\bR\x06stream\x126\n" +
+	"\x17max_concurrent_requests\x18\x04 \x01(\rR\x15maxConcurrentRequests\x128\n" +
+	"\tresponses\x18\x05 \x03(\v2\x1a.sebuf.http.ResponseConfigR\tresponses\x12K\n" +
+	"\x12openapi_extensions\x18\x06 \x03(\v2\x1c.sebuf.http.OpenAPIExtensionR\x11openapiExtensions\x12\x12\n" +
+	"\x04tags\x18\a \x03(\tR\x04tags\x12\x18\n" +
+	"\awebhook\x18\b \x01(\bR\awebhook\"\xdc\x01\n" +
 	"\rServiceConfig\x12\x1b\n" +
-	"\tbase_path\x18\x01 \x01(\tR\bbasePath\"'\n" +
+	"\tbase_path\x18\x01 \x01(\tR\bbasePath\x12\x12\n" +
+	"\x04host\x18\x02 \x01(\tR\x04host\x12!\n" +
+	"\fapi_versions\x18\x03 \x03(\tR\vapiVersions\x12K\n" +
+	"\x12openapi_extensions\x18\x04 \x03(\v2\x1c.sebuf.http.OpenAPIExtensionR\x11openapiExtensions\x12*\n" +
+	"\x04tags\x18\x05 \x03(\v2\x16.sebuf.http.OpenAPITagR\x04tags\"'\n" +
 	"\rFieldExamples\x12\x16\n" +
 	"\x06values\x18\x01 \x03(\tR\x06values\"=\n" +
 	"\vQueryConfig\x12\x12\n" +
@@ -902,7 +1187,19 @@ const file_sebuf_http_annotations_proto_rawDesc = "" +
 	"\brequired\x18\x02 \x01(\bR\brequired\"M\n" +
 	"\vOneofConfig\x12$\n" +
 	"\rdiscriminator\x18\x01 \x01(\tR\rdiscriminator\x12\x18\n" +
-	"\aflatten\x18\x02 \x01(\bR\aflatten*\x98\x01\n" +
+	"\aflatten\x18\x02 \x01(\bR\aflatten\"d\n" +
+	"\x0eResponseConfig\x12\x16\n" +
+	"\x06status\x18\x01 \x01(\rR\x06status\x12 \n" +
+	"\vdescription\x18\x02 \x01(\tR\vdescription\x12\x18\n" +
+	"\amessage\x18\x03 \x01(\tR\amessage\"<\n" +
+	"\x10OpenAPIExtension\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x12\x14\n" +
+	"\x05value\x18\x02 \x01(\tR\x05value\"n\n" +
+	"\n" +
+	"OpenAPITag\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x12 \n" +
+	"\vdescription\x18\x02 \x01(\tR\vdescription\x12*\n" +
+	"\x11external_docs_url\x18\x03 \x01(\tR\x0fexternalDocsUrl*\x98\x01\n" +
 	"\n" +
 	"HttpMethod\x12\x1b\n" +
 	"\x17HTTP_METHOD_UNSPECIFIED\x10\x00\x12\x13\n" +
@@ -969,7 +1266,7 @@ func file_sebuf_http_annotations_proto_rawDescGZIP() []byte {
 }
 
 var file_sebuf_http_annotations_proto_enumTypes = make([]protoimpl.EnumInfo, 6)
-var file_sebuf_http_annotations_proto_msgTypes = make([]protoimpl.MessageInfo, 5)
+var file_sebuf_http_annotations_proto_msgTypes = make([]protoimpl.MessageInfo, 8)
 var file_sebuf_http_annotations_proto_goTypes = []any{
 	(HttpMethod)(0),                       // 0: sebuf.http.HttpMethod
 	(Int64Encoding)(0),                    // 1: sebuf.http.Int64Encoding
@@ -982,45 +1279,52 @@ var file_sebuf_http_annotations_proto_goTypes = []any{
 	(*FieldExamples)(nil),                 // 8: sebuf.http.FieldExamples
 	(*QueryConfig)(nil),                   // 9: sebuf.http.QueryConfig
 	(*OneofConfig)(nil),                   // 10: sebuf.http.OneofConfig
-	(*descriptorpb.MethodOptions)(nil),    // 11: google.protobuf.MethodOptions
-	(*descriptorpb.ServiceOptions)(nil),   // 12: google.protobuf.ServiceOptions
-	(*descriptorpb.OneofOptions)(nil),     // 13: google.protobuf.OneofOptions
-	(*descriptorpb.FieldOptions)(nil),     // 14: google.protobuf.FieldOptions
-	(*descriptorpb.EnumValueOptions)(nil), // 15: google.protobuf.EnumValueOptions
+	(*ResponseConfig)(nil),                // 11: sebuf.http.ResponseConfig
+	(*OpenAPIExtension)(nil),              // 12: sebuf.http.OpenAPIExtension
+	(*OpenAPITag)(nil),                    // 13: sebuf.http.OpenAPITag
+	(*descriptorpb.MethodOptions)(nil),    // 14: google.protobuf.MethodOptions
+	(*descriptorpb.ServiceOptions)(nil),   // 15: google.protobuf.ServiceOptions
+	(*descriptorpb.OneofOptions)(nil),     // 16: google.protobuf.OneofOptions
+	(*descriptorpb.FieldOptions)(nil),     // 17: google.protobuf.FieldOptions
+	(*descriptorpb.EnumValueOptions)(nil), // 18: google.protobuf.EnumValueOptions
 }
 var file_sebuf_http_annotations_proto_depIdxs = []int32{
 	0,  // 0: sebuf.http.HttpConfig.method:type_name -> sebuf.http.HttpMethod
-	11, // 1: sebuf.http.config:extendee -> google.protobuf.MethodOptions
-	12, // 2: sebuf.http.service_config:extendee -> google.protobuf.ServiceOptions
-	13, // 3: sebuf.http.oneof_config:extendee -> google.protobuf.OneofOptions
-	14, // 4: sebuf.http.field_examples:extendee -> google.protobuf.FieldOptions
-	14, // 5: sebuf.http.query:extendee -> google.protobuf.FieldOptions
-	14, // 6: sebuf.http.unwrap:extendee -> google.protobuf.FieldOptions
-	14, // 7: sebuf.http.int64_encoding:extendee -> google.protobuf.FieldOptions
-	14, // 8: sebuf.http.enum_encoding:extendee -> google.protobuf.FieldOptions
-	14, // 9: sebuf.http.nullable:extendee -> google.protobuf.FieldOptions
-	14, // 10: sebuf.http.empty_behavior:extendee -> google.protobuf.FieldOptions
-	14, // 11: sebuf.http.timestamp_format:extendee -> google.protobuf.FieldOptions
-	14, // 12: sebuf.http.bytes_encoding:extendee -> google.protobuf.FieldOptions
-	14, // 13: sebuf.http.oneof_value:extendee -> google.protobuf.FieldOptions
-	14, // 14: sebuf.http.flatten:extendee -> google.protobuf.FieldOptions
-	14, // 15: sebuf.http.flatten_prefix:extendee -> google.protobuf.FieldOptions
-	15, // 16: sebuf.http.enum_value:extendee -> google.protobuf.EnumValueOptions
-	6,  // 17: sebuf.http.config:type_name -> sebuf.http.HttpConfig
-	7,  // 18: sebuf.http.service_config:type_name -> sebuf.http.ServiceConfig
-	10, // 19: sebuf.http.oneof_config:type_name -> sebuf.http.OneofConfig
-	8,  // 20: sebuf.http.field_examples:type_name -> sebuf.http.FieldExamples
-	9,  // 21: sebuf.http.query:type_name -> sebuf.http.QueryConfig
-	1,  // 22: sebuf.http.int64_encoding:type_name -> sebuf.http.Int64Encoding
-	2,  // 23: sebuf.http.enum_encoding:type_name -> sebuf.http.EnumEncoding
-	3,  // 24: sebuf.http.empty_behavior:type_name -> sebuf.http.EmptyBehavior
-	4,  // 25: sebuf.http.timestamp_format:type_name -> sebuf.http.TimestampFormat
-	5,  // 26: sebuf.http.bytes_encoding:type_name -> sebuf.http.BytesEncoding
-	27, // [27:27] is the sub-list for method output_type
-	27, // [27:27] is the sub-list for method input_type
-	17, // [17:27] is the sub-list for extension type_name
-	1,  // [1:17] is the sub-list for extension extendee
-	0,  // [0:1] is the sub-list for field type_name
+	11, // 1: sebuf.http.HttpConfig.responses:type_name -> sebuf.http.ResponseConfig
+	12, // 2: sebuf.http.HttpConfig.openapi_extensions:type_name -> sebuf.http.OpenAPIExtension
+	12, // 3: sebuf.http.ServiceConfig.openapi_extensions:type_name -> sebuf.http.OpenAPIExtension
+	13, // 4: sebuf.http.ServiceConfig.tags:type_name -> sebuf.http.OpenAPITag
+	14, // 5: sebuf.http.config:extendee -> google.protobuf.MethodOptions
+	15, // 6: sebuf.http.service_config:extendee -> google.protobuf.ServiceOptions
+	16, // 7: sebuf.http.oneof_config:extendee -> google.protobuf.OneofOptions
+	17, // 8: sebuf.http.field_examples:extendee -> google.protobuf.FieldOptions
+	17, // 9: sebuf.http.query:extendee -> google.protobuf.FieldOptions
+	17, // 10: sebuf.http.unwrap:extendee -> google.protobuf.FieldOptions
+	17, // 11: sebuf.http.int64_encoding:extendee -> google.protobuf.FieldOptions
+	17, // 12: sebuf.http.enum_encoding:extendee -> google.protobuf.FieldOptions
+	17, // 13: sebuf.http.nullable:extendee -> google.protobuf.FieldOptions
+	17, // 14: sebuf.http.empty_behavior:extendee -> google.protobuf.FieldOptions
+	17, // 15: sebuf.http.timestamp_format:extendee -> google.protobuf.FieldOptions
+	17, // 16: sebuf.http.bytes_encoding:extendee -> google.protobuf.FieldOptions
+	17, // 17: sebuf.http.oneof_value:extendee -> google.protobuf.FieldOptions
+	17, // 18: sebuf.http.flatten:extendee -> google.protobuf.FieldOptions
+	17, // 19: sebuf.http.flatten_prefix:extendee -> google.protobuf.FieldOptions
+	18, // 20: sebuf.http.enum_value:extendee -> google.protobuf.EnumValueOptions
+	6,  // 21: sebuf.http.config:type_name -> sebuf.http.HttpConfig
+	7,  // 22: sebuf.http.service_config:type_name -> sebuf.http.ServiceConfig
+	10, // 23: sebuf.http.oneof_config:type_name -> sebuf.http.OneofConfig
+	8,  // 24: sebuf.http.field_examples:type_name -> sebuf.http.FieldExamples
+	9,  // 25: sebuf.http.query:type_name -> sebuf.http.QueryConfig
+	1,  // 26: sebuf.http.int64_encoding:type_name -> sebuf.http.Int64Encoding
+	2,  // 27: sebuf.http.enum_encoding:type_name -> sebuf.http.EnumEncoding
+	3,  // 28: sebuf.http.empty_behavior:type_name -> sebuf.http.EmptyBehavior
+	4,  // 29: sebuf.http.timestamp_format:type_name -> sebuf.http.TimestampFormat
+	5,  // 30: sebuf.http.bytes_encoding:type_name -> sebuf.http.BytesEncoding
+	31, // [31:31] is the sub-list for method output_type
+	31, // [31:31] is the sub-list for method input_type
+	21, // [21:31] is the sub-list for extension type_name
+	5,  // [5:21] is the sub-list for extension extendee
+	0,  // [0:5] is the sub-list for field type_name
 }
 
 func init() { file_sebuf_http_annotations_proto_init() }
@@ -1034,7 +1338,7 @@ func file_sebuf_http_annotations_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_sebuf_http_annotations_proto_rawDesc), len(file_sebuf_http_annotations_proto_rawDesc)),
 			NumEnums:      6,
-			NumMessages:   5,
+			NumMessages:   8,
 			NumExtensions: 16,
 			NumServices:   0,
 		},
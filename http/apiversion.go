@@ -0,0 +1,61 @@
+package http
+
+import (
+	"context"
+	"fmt"
+	stdhttp "net/http"
+	"slices"
+	"strings"
+)
+
+// APIVersionHeader is the request header carrying the API version a client targets.
+const APIVersionHeader = "X-API-Version"
+
+type apiVersionCtxKey struct{}
+
+// RequireAPIVersion wraps next so that requests must target one of the supported API
+// versions through the X-API-Version header. A request without the header is served
+// with the first supported version. A request naming any other version is rejected with
+// 406 Not Acceptable: the supported versions are listed in the X-API-Version header, and
+// an *Error naming them is written through writeError. A nil writeError writes the Error
+// as JSON.
+//
+// The negotiated version is available to handlers through APIVersionFromContext.
+// Generated handlers are wrapped automatically for services annotated with
+// service_config api_versions. With no supported versions, next is returned unchanged.
+func RequireAPIVersion(supported []string, writeError ErrorWriter, next stdhttp.Handler) stdhttp.Handler {
+	if len(supported) == 0 {
+		return next
+	}
+	if writeError == nil {
+		writeError = writeJSONError
+	}
+
+	versions := slices.Clone(supported)
+	listed := strings.Join(versions, ", ")
+
+	return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, r *stdhttp.Request) {
+		version := strings.TrimSpace(r.Header.Get(APIVersionHeader))
+		if version == "" {
+			version = versions[0]
+		}
+
+		if !slices.Contains(versions, version) {
+			w.Header().Set(APIVersionHeader, listed)
+			writeError(w, r, stdhttp.StatusNotAcceptable, &Error{
+				Message: fmt.Sprintf("unsupported API version %q, supported versions: %s", version, listed),
+			})
+			return
+		}
+
+		ctx := context.WithValue(r.Context(), apiVersionCtxKey{}, version)
+		next.ServeHTTP(w, r.WithContext(ctx))
+	})
+}
+
+// APIVersionFromContext returns the API version negotiated by RequireAPIVersion, or ""
+// if the request was not served through it.
+func APIVersionFromContext(ctx context.Context) string {
+	version, _ := ctx.Value(apiVersionCtxKey{}).(string)
+	return version
+}
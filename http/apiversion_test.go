@@ -0,0 +1,87 @@
+package http_test
+
+import (
+	"errors"
+	stdhttp "net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestRequireAPIVersion(t *testing.T) {
+	var negotiated string
+	handler := http.RequireAPIVersion([]string{"v1", "v2"}, nil, stdhttp.HandlerFunc(
+		func(w stdhttp.ResponseWriter, r *stdhttp.Request) {
+			negotiated = http.APIVersionFromContext(r.Context())
+			w.WriteHeader(stdhttp.StatusOK)
+		},
+	))
+
+	tests := []struct {
+		name           string
+		header         string
+		wantStatus     int
+		wantNegotiated string
+	}{
+		{"header absent uses first version", "", stdhttp.StatusOK, "v1"},
+		{"supported version", "v2", stdhttp.StatusOK, "v2"},
+		{"unsupported version", "v3", stdhttp.StatusNotAcceptable, ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			negotiated = ""
+			req := httptest.NewRequest(stdhttp.MethodGet, "/notes", nil)
+			if tt.header != "" {
+				req.Header.Set(http.APIVersionHeader, tt.header)
+			}
+			rec := httptest.NewRecorder()
+			handler.ServeHTTP(rec, req)
+
+			if rec.Code != tt.wantStatus {
+				t.Fatalf("status = %d, want %d", rec.Code, tt.wantStatus)
+			}
+			if negotiated != tt.wantNegotiated {
+				t.Errorf("negotiated version = %q, want %q", negotiated, tt.wantNegotiated)
+			}
+			if tt.wantStatus == stdhttp.StatusNotAcceptable {
+				if got := rec.Header().Get(http.APIVersionHeader); got != "v1, v2" {
+					t.Errorf("%s = %q, want supported versions", http.APIVersionHeader, got)
+				}
+				if !strings.Contains(rec.Body.String(), "supported versions: v1, v2") {
+					t.Errorf("unexpected body: %s", rec.Body.String())
+				}
+			}
+		})
+	}
+}
+
+func TestRequireAPIVersionErrorWriter(t *testing.T) {
+	var gotStatus int
+	var gotErr error
+	writeError := func(w stdhttp.ResponseWriter, _ *stdhttp.Request, statusCode int, err error) {
+		gotStatus, gotErr = statusCode, err
+		w.WriteHeader(statusCode)
+	}
+	handler := http.RequireAPIVersion([]string{"v1"}, writeError, stdhttp.HandlerFunc(
+		func(w stdhttp.ResponseWriter, _ *stdhttp.Request) { w.WriteHeader(stdhttp.StatusOK) },
+	))
+
+	req := httptest.NewRequest(stdhttp.MethodGet, "/notes", nil)
+	req.Header.Set(http.APIVersionHeader, "v2")
+	rec := httptest.NewRecorder()
+	handler.ServeHTTP(rec, req)
+
+	if gotStatus != stdhttp.StatusNotAcceptable {
+		t.Errorf("ErrorWriter status = %d, want %d", gotStatus, stdhttp.StatusNotAcceptable)
+	}
+	var apiErr *http.Error
+	if !errors.As(gotErr, &apiErr) || !strings.Contains(apiErr.GetMessage(), "supported versions: v1") {
+		t.Errorf("ErrorWriter error = %v, want an *http.Error listing the versions", gotErr)
+	}
+	if got := rec.Header().Get(http.APIVersionHeader); got != "v1" {
+		t.Errorf("%s = %q, want supported versions", http.APIVersionHeader, got)
+	}
+}
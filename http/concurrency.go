@@ -0,0 +1,41 @@
+package http
+
+import (
+	stdhttp "net/http"
+	"strconv"
+)
+
+// ConcurrencyLimitRetryAfter is the Retry-After value, in seconds, sent when a request
+// is rejected by ConcurrencyLimit.
+const ConcurrencyLimitRetryAfter = 1
+
+// ConcurrencyLimit wraps next so that at most limit requests are handled at the same
+// time. Requests arriving while the limit is reached are not queued: they are rejected
+// with 503 Service Unavailable and a Retry-After header, and an *Error is written
+// through writeError. A nil writeError writes the Error as JSON.
+//
+// Generated handlers are wrapped automatically for methods annotated with
+// max_concurrent_requests. A limit of zero or less returns next unchanged.
+func ConcurrencyLimit(limit int, writeError ErrorWriter, next stdhttp.Handler) stdhttp.Handler {
+	if limit <= 0 {
+		return next
+	}
+	if writeError == nil {
+		writeError = writeJSONError
+	}
+
+	slots := make(chan struct{}, limit)
+	retryAfter := strconv.Itoa(ConcurrencyLimitRetryAfter)
+
+	return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, r *stdhttp.Request) {
+		select {
+		case slots <- struct{}{}:
+			defer func() { <-slots }()
+			next.ServeHTTP(w, r)
+		default:
+			w.Header().Set("Retry-After", retryAfter)
+			writeError(w, r, stdhttp.StatusServiceUnavailable,
+				&Error{Message: "too many concurrent requests, retry later"})
+		}
+	})
+}
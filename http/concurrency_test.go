@@ -0,0 +1,93 @@
+package http_test
+
+import (
+	"errors"
+	stdhttp "net/http"
+	"net/http/httptest"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestConcurrencyLimit(t *testing.T) {
+	entered := make(chan struct{})
+	release := make(chan struct{})
+	handler := http.ConcurrencyLimit(1, nil, stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, _ *stdhttp.Request) {
+		entered <- struct{}{}
+		<-release
+		w.WriteHeader(stdhttp.StatusOK)
+	}))
+
+	var wg sync.WaitGroup
+	first := httptest.NewRecorder()
+	wg.Add(1)
+	go func() {
+		defer wg.Done()
+		handler.ServeHTTP(first, httptest.NewRequest(stdhttp.MethodPost, "/reports", nil))
+	}()
+	<-entered
+
+	rejected := httptest.NewRecorder()
+	handler.ServeHTTP(rejected, httptest.NewRequest(stdhttp.MethodPost, "/reports", nil))
+	if rejected.Code != stdhttp.StatusServiceUnavailable {
+		t.Errorf("status while limit reached = %d, want %d", rejected.Code, stdhttp.StatusServiceUnavailable)
+	}
+	if got := rejected.Header().Get("Retry-After"); got != "1" {
+		t.Errorf("Retry-After = %q, want %q", got, "1")
+	}
+	if got := rejected.Header().Get("Content-Type"); got != "application/json" {
+		t.Errorf("Content-Type = %q, want application/json", got)
+	}
+	if !strings.Contains(rejected.Body.String(), "too many concurrent requests") {
+		t.Errorf("unexpected body: %s", rejected.Body.String())
+	}
+
+	close(release)
+	wg.Wait()
+	if first.Code != stdhttp.StatusOK {
+		t.Errorf("first request status = %d, want %d", first.Code, stdhttp.StatusOK)
+	}
+
+	// The slot is released once the in-flight request completes.
+	again := httptest.NewRecorder()
+	go func() { <-entered }()
+	handler.ServeHTTP(again, httptest.NewRequest(stdhttp.MethodPost, "/reports", nil))
+	if again.Code != stdhttp.StatusOK {
+		t.Errorf("status after release = %d, want %d", again.Code, stdhttp.StatusOK)
+	}
+}
+
+func TestConcurrencyLimitErrorWriter(t *testing.T) {
+	release := make(chan struct{})
+	defer close(release)
+	entered := make(chan struct{})
+
+	var gotStatus int
+	var gotErr error
+	writeError := func(w stdhttp.ResponseWriter, _ *stdhttp.Request, statusCode int, err error) {
+		gotStatus, gotErr = statusCode, err
+		w.WriteHeader(statusCode)
+	}
+	handler := http.ConcurrencyLimit(1, writeError, stdhttp.HandlerFunc(func(stdhttp.ResponseWriter, *stdhttp.Request) {
+		close(entered)
+		<-release
+	}))
+
+	go handler.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest(stdhttp.MethodPost, "/reports", nil))
+	<-entered
+
+	rejected := httptest.NewRecorder()
+	handler.ServeHTTP(rejected, httptest.NewRequest(stdhttp.MethodPost, "/reports", nil))
+	if gotStatus != stdhttp.StatusServiceUnavailable {
+		t.Errorf("ErrorWriter status = %d, want %d", gotStatus, stdhttp.StatusServiceUnavailable)
+	}
+	var apiErr *http.Error
+	if !errors.As(gotErr, &apiErr) || !strings.Contains(apiErr.GetMessage(), "too many concurrent requests") {
+		t.Errorf("ErrorWriter error = %v, want an *http.Error", gotErr)
+	}
+	if got := rejected.Header().Get("Retry-After"); got != "1" {
+		t.Errorf("Retry-After = %q, want %q", got, "1")
+	}
+}
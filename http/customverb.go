@@ -0,0 +1,38 @@
+package http
+
+import (
+	stdhttp "net/http"
+	"strings"
+)
+
+// VerbHandlers maps the custom verbs of methods sharing a path, such as "activate" in
+// "/users/{id}:activate", to their handlers. The empty verb maps to the handler of the
+// path without a verb, if there is one.
+type VerbHandlers map[string]stdhttp.Handler
+
+// CustomVerbs serves AIP-136 custom methods, whose last path segment is a path variable
+// followed by ":verb". net/http.ServeMux wildcards span whole segments, so such paths are
+// registered without the verb ("/users/{id}") and CustomVerbs dispatches them: it strips
+// a known verb off the value of the param wildcard, sets param to what remains and calls
+// that verb's handler. A segment without a known verb goes to the empty verb's handler
+// with its value unchanged; requests no handler matches get 404 Not Found.
+//
+// Generated Register functions use it for methods annotated with custom verb paths.
+func CustomVerbs(param string, handlers VerbHandlers) stdhttp.Handler {
+	return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, r *stdhttp.Request) {
+		value, verb := r.PathValue(param), ""
+		if i := strings.LastIndexByte(value, ':'); i >= 0 {
+			if _, ok := handlers[value[i+1:]]; ok && i+1 < len(value) {
+				value, verb = value[:i], value[i+1:]
+			}
+		}
+
+		handler, ok := handlers[verb]
+		if !ok {
+			stdhttp.NotFound(w, r)
+			return
+		}
+		r.SetPathValue(param, value)
+		handler.ServeHTTP(w, r)
+	})
+}
@@ -0,0 +1,61 @@
+package http_test
+
+import (
+	stdhttp "net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestCustomVerbs(t *testing.T) {
+	handlerFor := func(name string) stdhttp.Handler {
+		return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, r *stdhttp.Request) {
+			w.Header().Set("X-Handler", name)
+			w.Header().Set("X-Id", r.PathValue("id"))
+		})
+	}
+	mux := stdhttp.NewServeMux()
+	mux.Handle("POST /v1/users/{id}", http.CustomVerbs("id", http.VerbHandlers{
+		"activate":   handlerFor("activate"),
+		"deactivate": handlerFor("deactivate"),
+		"":           handlerFor("update"),
+	}))
+	mux.Handle("GET /v1/users/{id}", http.CustomVerbs("id", http.VerbHandlers{
+		"preview": handlerFor("preview"),
+	}))
+
+	tests := []struct {
+		name        string
+		method      string
+		path        string
+		wantStatus  int
+		wantHandler string
+		wantID      string
+	}{
+		{"verb", stdhttp.MethodPost, "/v1/users/42:activate", stdhttp.StatusOK, "activate", "42"},
+		{"other verb", stdhttp.MethodPost, "/v1/users/42:deactivate", stdhttp.StatusOK, "deactivate", "42"},
+		{"no verb", stdhttp.MethodPost, "/v1/users/42", stdhttp.StatusOK, "update", "42"},
+		{"unknown verb keeps the colon", stdhttp.MethodPost, "/v1/users/a:b", stdhttp.StatusOK, "update", "a:b"},
+		{"escaped colon", stdhttp.MethodPost, "/v1/users/42%3Aactivate", stdhttp.StatusOK, "activate", "42"},
+		{"verb only route", stdhttp.MethodGet, "/v1/users/42:preview", stdhttp.StatusOK, "preview", "42"},
+		{"verb only route without verb", stdhttp.MethodGet, "/v1/users/42", stdhttp.StatusNotFound, "", ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			rec := httptest.NewRecorder()
+			mux.ServeHTTP(rec, httptest.NewRequest(tt.method, tt.path, nil))
+
+			if rec.Code != tt.wantStatus {
+				t.Fatalf("status = %d, want %d", rec.Code, tt.wantStatus)
+			}
+			if got := rec.Header().Get("X-Handler"); got != tt.wantHandler {
+				t.Errorf("handler = %q, want %q", got, tt.wantHandler)
+			}
+			if got := rec.Header().Get("X-Id"); got != tt.wantID {
+				t.Errorf("id = %q, want %q", got, tt.wantID)
+			}
+		})
+	}
+}
@@ -0,0 +1,22 @@
+package http
+
+import (
+	stdhttp "net/http"
+
+	"google.golang.org/protobuf/encoding/protojson"
+)
+
+// ErrorWriter writes err as the response to r with the given status code. The handler
+// wrappers in this package reject requests through an ErrorWriter, so that a generated
+// server renders those rejections with its own ErrorHandler, marshal options and content
+// negotiation; generated Register functions pass one automatically.
+type ErrorWriter func(w stdhttp.ResponseWriter, r *stdhttp.Request, statusCode int, err error)
+
+// writeJSONError is the ErrorWriter used when a wrapper is given none. It writes err as
+// an Error body in JSON.
+func writeJSONError(w stdhttp.ResponseWriter, _ *stdhttp.Request, statusCode int, err error) {
+	body, _ := protojson.Marshal(&Error{Message: err.Error()})
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(statusCode)
+	_, _ = w.Write(body)
+}
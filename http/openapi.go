@@ -0,0 +1,87 @@
+package http
+
+import (
+	"bytes"
+	"fmt"
+	"html/template"
+	stdhttp "net/http"
+)
+
+// Default routes registered by RegisterOpenAPIRoutes.
+const (
+	DefaultOpenAPIYAMLPath = "/openapi.yaml"
+	DefaultOpenAPIJSONPath = "/openapi.json"
+	DefaultOpenAPIDocsPath = "/docs"
+)
+
+// swaggerUIPage is a minimal Swagger UI page that loads swagger-ui-dist from a CDN and
+// points it at the served spec. The spec URL is the only dynamic value.
+const swaggerUIPage = `<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8" />
+  <meta name="viewport" content="width=device-width, initial-scale=1" />
+  <title>API Documentation</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+  <script>
+    window.onload = function () {
+      window.ui = SwaggerUIBundle({ url: "%s", dom_id: "#swagger-ui" });
+    };
+  </script>
+</body>
+</html>
+`
+
+// OpenAPISpecHandler returns a handler that serves an OpenAPI document produced by
+// protoc-gen-openapiv3. The Content-Type is derived from the document itself: JSON
+// output (format=json) is served as application/json, anything else as application/yaml.
+//
+// The spec is usually embedded next to the server code:
+//
+//	//go:embed UserService.openapi.yaml
+//	var openAPISpec []byte
+func OpenAPISpecHandler(spec []byte) stdhttp.Handler {
+	contentType := "application/yaml"
+	if isJSONDocument(spec) {
+		contentType = "application/json"
+	}
+
+	return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, _ *stdhttp.Request) {
+		w.Header().Set("Content-Type", contentType)
+		_, _ = w.Write(spec)
+	})
+}
+
+// SwaggerUIHandler returns a handler that serves a minimal Swagger UI page rendering
+// the OpenAPI document found at specURL.
+func SwaggerUIHandler(specURL string) stdhttp.Handler {
+	page := fmt.Sprintf(swaggerUIPage, template.JSEscapeString(specURL))
+
+	return stdhttp.HandlerFunc(func(w stdhttp.ResponseWriter, _ *stdhttp.Request) {
+		w.Header().Set("Content-Type", "text/html; charset=utf-8")
+		_, _ = w.Write([]byte(page))
+	})
+}
+
+// RegisterOpenAPIRoutes registers GET routes on mux that make a running service
+// self-documenting: the spec itself (at /openapi.yaml, or /openapi.json for JSON
+// documents) and a Swagger UI page at /docs that renders it.
+func RegisterOpenAPIRoutes(mux *stdhttp.ServeMux, spec []byte) {
+	specPath := DefaultOpenAPIYAMLPath
+	if isJSONDocument(spec) {
+		specPath = DefaultOpenAPIJSONPath
+	}
+
+	mux.Handle("GET "+specPath, OpenAPISpecHandler(spec))
+	mux.Handle("GET "+DefaultOpenAPIDocsPath, SwaggerUIHandler(specPath))
+}
+
+// isJSONDocument reports whether the spec is a JSON document rather than YAML.
+func isJSONDocument(spec []byte) bool {
+	trimmed := bytes.TrimSpace(spec)
+	return len(trimmed) > 0 && trimmed[0] == '{'
+}
@@ -0,0 +1,103 @@
+package http_test
+
+import (
+	"io"
+	stdhttp "net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+const (
+	yamlSpec = "openapi: 3.1.0\ninfo:\n  title: UserService API\n  version: 1.0.0\n"
+	jsonSpec = `{"openapi":"3.1.0","info":{"title":"UserService API","version":"1.0.0"}}`
+)
+
+func serve(t *testing.T, mux *stdhttp.ServeMux, method, target string) *stdhttp.Response {
+	t.Helper()
+	rec := httptest.NewRecorder()
+	mux.ServeHTTP(rec, httptest.NewRequest(method, target, nil))
+	return rec.Result()
+}
+
+func readBody(t *testing.T, resp *stdhttp.Response) string {
+	t.Helper()
+	defer resp.Body.Close()
+	body, err := io.ReadAll(resp.Body)
+	if err != nil {
+		t.Fatalf("reading response body: %v", err)
+	}
+	return string(body)
+}
+
+func TestRegisterOpenAPIRoutes(t *testing.T) {
+	tests := []struct {
+		name            string
+		spec            string
+		specPath        string
+		wantContentType string
+	}{
+		{
+			name:            "yaml spec",
+			spec:            yamlSpec,
+			specPath:        http.DefaultOpenAPIYAMLPath,
+			wantContentType: "application/yaml",
+		},
+		{
+			name:            "json spec",
+			spec:            jsonSpec,
+			specPath:        http.DefaultOpenAPIJSONPath,
+			wantContentType: "application/json",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			mux := stdhttp.NewServeMux()
+			http.RegisterOpenAPIRoutes(mux, []byte(tt.spec))
+
+			resp := serve(t, mux, stdhttp.MethodGet, tt.specPath)
+			if resp.StatusCode != stdhttp.StatusOK {
+				t.Fatalf("GET %s status = %d, want %d", tt.specPath, resp.StatusCode, stdhttp.StatusOK)
+			}
+			if got := resp.Header.Get("Content-Type"); got != tt.wantContentType {
+				t.Errorf("Content-Type = %q, want %q", got, tt.wantContentType)
+			}
+			if got := readBody(t, resp); got != tt.spec {
+				t.Errorf("spec body = %q, want %q", got, tt.spec)
+			}
+
+			resp = serve(t, mux, stdhttp.MethodGet, http.DefaultOpenAPIDocsPath)
+			if resp.StatusCode != stdhttp.StatusOK {
+				t.Fatalf("GET %s status = %d, want %d", http.DefaultOpenAPIDocsPath, resp.StatusCode, stdhttp.StatusOK)
+			}
+			if got := readBody(t, resp); !strings.Contains(got, `url: "`+tt.specPath+`"`) {
+				t.Errorf("Swagger UI page does not reference %s:\n%s", tt.specPath, got)
+			}
+		})
+	}
+}
+
+func TestRegisterOpenAPIRoutes_OnlyGet(t *testing.T) {
+	mux := stdhttp.NewServeMux()
+	http.RegisterOpenAPIRoutes(mux, []byte(yamlSpec))
+
+	resp := serve(t, mux, stdhttp.MethodPost, http.DefaultOpenAPIYAMLPath)
+	if resp.StatusCode != stdhttp.StatusMethodNotAllowed {
+		t.Errorf("POST %s status = %d, want %d",
+			http.DefaultOpenAPIYAMLPath, resp.StatusCode, stdhttp.StatusMethodNotAllowed)
+	}
+}
+
+func TestSwaggerUIHandler_EscapesSpecURL(t *testing.T) {
+	rec := httptest.NewRecorder()
+	handler := http.SwaggerUIHandler(`/spec.yaml"</script><script>alert(1)</script>`)
+	handler.ServeHTTP(rec, httptest.NewRequest(stdhttp.MethodGet, "/docs", nil))
+
+	body := rec.Body.String()
+	if strings.Contains(body, "<script>alert(1)</script>") {
+		t.Errorf("spec URL was not escaped:\n%s", body)
+	}
+}
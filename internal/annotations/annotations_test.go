@@ -128,6 +128,34 @@ func TestExtractPathParams(t *testing.T) {
 	}
 }
 
+func TestSplitCustomVerb(t *testing.T) {
+	tests := []struct {
+		name     string
+		path     string
+		wantPath string
+		wantVerb string
+	}{
+		{"verb after variable", "/v1/users/{id}:activate", "/v1/users/{id}", "activate"},
+		{"camelCase verb", "/v1/users/{user_id}:sendEmail", "/v1/users/{user_id}", "sendEmail"},
+		{"no verb", "/v1/users/{id}", "/v1/users/{id}", ""},
+		{"verb after literal segment", "/v1/users:batchGet", "/v1/users:batchGet", ""},
+		{"verb in own segment", "/v1/users/{id}/activate", "/v1/users/{id}/activate", ""},
+		{"verb not in last segment", "/v1/users/{id}:activate/posts", "/v1/users/{id}:activate/posts", ""},
+		{"empty verb", "/v1/users/{id}:", "/v1/users/{id}:", ""},
+		{"variable in verb", "/v1/{a}:{b}", "/v1/{a}:{b}", ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			gotPath, gotVerb := SplitCustomVerb(tt.path)
+			if gotPath != tt.wantPath || gotVerb != tt.wantVerb {
+				t.Errorf("SplitCustomVerb(%q) = %q, %q, expected %q, %q",
+					tt.path, gotPath, gotVerb, tt.wantPath, tt.wantVerb)
+			}
+		})
+	}
+}
+
 func TestBuildHTTPPath(t *testing.T) {
 	tests := []struct {
 		name        string
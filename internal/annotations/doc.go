@@ -9,14 +9,19 @@
 //
 // Each annotation concept lives in its own file with standardized function signatures:
 //
-//   - http_config.go:    GetMethodHTTPConfig, GetServiceBasePath
+//   - http_config.go:    GetMethodHTTPConfig, GetServiceBasePath, GetServiceHost,
+//     GetServiceAPIVersions
 //   - headers.go:        GetServiceHeaders, GetMethodHeaders, CombineHeaders
 //   - query.go:          GetQueryParams
 //   - unwrap.go:         HasUnwrapAnnotation, GetUnwrapField, FindUnwrapField, IsRootUnwrap
 //   - field_examples.go: GetFieldExamples
+//   - responses.go:      GetMethodResponses
+//   - openapi_extensions.go: GetOpenAPIExtensions
+//   - tags.go:           GetServiceTags, GetOperationTags
 //   - path.go:           ExtractPathParams, BuildHTTPPath, EnsureLeadingSlash
 //   - method.go:         HTTPMethodToString, HTTPMethodToLower
 //   - helpers.go:        LowerFirst
+//   - well_known.go:     GetWellKnownType, IsWellKnownField
 //
 // To add a new annotation type, create a new file following this pattern:
 //
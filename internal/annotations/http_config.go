@@ -10,20 +10,62 @@ import (
 
 // HTTPConfig represents the HTTP configuration for a method.
 type HTTPConfig struct {
-	Path       string
-	Method     string   // "GET", "POST", "PUT", "DELETE", "PATCH"
-	PathParams []string // Path variable names extracted from path
-	Stream     bool     // When true, this method uses SSE streaming
+	Path                  string
+	Method                string   // "GET", "POST", "PUT", "DELETE", "PATCH"
+	PathParams            []string // Path variable names extracted from path
+	Stream                bool     // When true, this method uses SSE streaming
+	MaxConcurrentRequests uint32   // Concurrent request limit; zero means unlimited
+	Webhook               bool     // When true, the method documents an outgoing webhook
 }
 
 // ServiceConfig represents the HTTP configuration for a service.
 type ServiceConfig struct {
-	BasePath string
+	BasePath    string
+	Host        string
+	APIVersions []string
 }
 
 // GetMethodHTTPConfig extracts HTTP configuration from method options.
 // Returns nil if no HTTP config annotation is present.
 func GetMethodHTTPConfig(method *protogen.Method) *HTTPConfig {
+	httpConfig := getMethodConfig(method)
+	if httpConfig == nil {
+		return nil
+	}
+
+	path := httpConfig.GetPath()
+
+	return &HTTPConfig{
+		Path:                  path,
+		Method:                HTTPMethodToString(httpConfig.GetMethod()),
+		PathParams:            ExtractPathParams(path),
+		Stream:                httpConfig.GetStream(),
+		MaxConcurrentRequests: httpConfig.GetMaxConcurrentRequests(),
+		Webhook:               httpConfig.GetWebhook(),
+	}
+}
+
+// GetServiceBasePath extracts the base path from service options.
+// Returns an empty string if no service config annotation is present.
+func GetServiceBasePath(service *protogen.Service) string {
+	return getServiceConfig(service).GetBasePath()
+}
+
+// GetServiceHost extracts the host (including scheme) from service options.
+// Returns an empty string if no service config annotation or host is present.
+func GetServiceHost(service *protogen.Service) string {
+	return getServiceConfig(service).GetHost()
+}
+
+// GetServiceAPIVersions extracts the supported API versions from service options.
+// Returns nil if no service config annotation or versions are present.
+func GetServiceAPIVersions(service *protogen.Service) []string {
+	return getServiceConfig(service).GetApiVersions()
+}
+
+// getMethodConfig extracts the raw config annotation from method options.
+// Returns nil if the annotation is not present; the generated getters are nil-safe.
+func getMethodConfig(method *protogen.Method) *http.HttpConfig {
 	options := method.Desc.Options()
 	if options == nil {
 		return nil
@@ -40,42 +82,35 @@ func GetMethodHTTPConfig(method *protogen.Method) *HTTPConfig {
 	}
 
 	httpConfig, ok := ext.(*http.HttpConfig)
-	if !ok || httpConfig == nil {
+	if !ok {
 		return nil
 	}
 
-	path := httpConfig.GetPath()
-
-	return &HTTPConfig{
-		Path:       path,
-		Method:     HTTPMethodToString(httpConfig.GetMethod()),
-		PathParams: ExtractPathParams(path),
-		Stream:     httpConfig.GetStream(),
-	}
+	return httpConfig
 }
 
-// GetServiceBasePath extracts the base path from service options.
-// Returns an empty string if no service config annotation is present.
-func GetServiceBasePath(service *protogen.Service) string {
+// getServiceConfig extracts the raw service_config annotation from service options.
+// Returns nil if the annotation is not present; the generated getters are nil-safe.
+func getServiceConfig(service *protogen.Service) *http.ServiceConfig {
 	options := service.Desc.Options()
 	if options == nil {
-		return ""
+		return nil
 	}
 
 	serviceOptions, ok := options.(*descriptorpb.ServiceOptions)
 	if !ok {
-		return ""
+		return nil
 	}
 
 	ext := proto.GetExtension(serviceOptions, http.E_ServiceConfig)
 	if ext == nil {
-		return ""
+		return nil
 	}
 
 	serviceConfig, ok := ext.(*http.ServiceConfig)
-	if !ok || serviceConfig == nil {
-		return ""
+	if !ok {
+		return nil
 	}
 
-	return serviceConfig.GetBasePath()
+	return serviceConfig
 }
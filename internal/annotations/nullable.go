@@ -41,6 +41,22 @@ func IsNullableField(field *protogen.Field) bool {
 	return ok && nullable
 }
 
+// HasExplicitPresence reports whether a singular scalar or enum field tracks presence,
+// as a proto3 `optional` field does. Edition files have no optional keyword: there a
+// field has explicit presence unless features.field_presence is IMPLICIT, so
+// HasOptionalKeyword alone misses it. Message and oneof fields are not included.
+func HasExplicitPresence(field *protogen.Field) bool {
+	desc := field.Desc
+	if desc.HasOptionalKeyword() {
+		return true
+	}
+	return desc.ParentFile().Syntax() == protoreflect.Editions &&
+		desc.Cardinality() == protoreflect.Optional &&
+		desc.HasPresence() &&
+		desc.ContainingOneof() == nil &&
+		desc.Message() == nil
+}
+
 // ValidateNullableAnnotation checks if nullable annotation is valid for a field.
 // Returns error if nullable=true on a non-optional field or on a message field.
 func ValidateNullableAnnotation(field *protogen.Field, messageName string) error {
@@ -49,7 +65,7 @@ func ValidateNullableAnnotation(field *protogen.Field, messageName string) error
 	}
 
 	// Nullable only valid on proto3 optional fields
-	if !field.Desc.HasOptionalKeyword() {
+	if !HasExplicitPresence(field) {
 		return &NullableValidationError{
 			MessageName: messageName,
 			FieldName:   string(field.Desc.Name()),
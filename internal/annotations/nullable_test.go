@@ -0,0 +1,70 @@
+package annotations
+
+import (
+	"testing"
+
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+)
+
+// presenceFile builds a Record message whose fields cover each presence rule: an int32
+// field, the same with field_presence IMPLICIT, a repeated field and a message field.
+func presenceFile(syntax string, edition descriptorpb.Edition) *descriptorpb.FileDescriptorProto {
+	implicit := scalarField("implicit_name", 2)
+	labels := scalarField("labels", 3)
+	labels.Label = descriptorpb.FieldDescriptorProto_LABEL_REPEATED.Enum()
+	nested := scalarField("nested", 4)
+	nested.Type = descriptorpb.FieldDescriptorProto_TYPE_MESSAGE.Enum()
+	nested.TypeName = proto.String("." + validateTestPkg + ".Record")
+
+	fd := &descriptorpb.FileDescriptorProto{
+		Name:    proto.String("presence.proto"),
+		Package: proto.String(validateTestPkg),
+		Syntax:  proto.String(syntax),
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("github.com/SebastienMelki/sebuf/internal/annotations/validatev1"),
+		},
+		MessageType: []*descriptorpb.DescriptorProto{{
+			Name:  proto.String("Record"),
+			Field: []*descriptorpb.FieldDescriptorProto{scalarField("name", 1), implicit, labels, nested},
+		}},
+	}
+	if syntax == "editions" {
+		fd.Edition = edition.Enum()
+		implicit.Options = &descriptorpb.FieldOptions{Features: &descriptorpb.FeatureSet{
+			FieldPresence: descriptorpb.FeatureSet_IMPLICIT.Enum(),
+		}}
+	}
+	return fd
+}
+
+func TestHasExplicitPresence(t *testing.T) {
+	tests := []struct {
+		name string
+		file *descriptorpb.FileDescriptorProto
+		want map[string]bool
+	}{
+		{
+			name: "edition 2023",
+			file: presenceFile("editions", descriptorpb.Edition_EDITION_2023),
+			want: map[string]bool{"name": true, "implicit_name": false, "labels": false, "nested": false},
+		},
+		{
+			name: "proto3",
+			file: presenceFile("proto3", descriptorpb.Edition_EDITION_UNKNOWN),
+			want: map[string]bool{"name": false, "implicit_name": false, "labels": false, "nested": false},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			msg := findValidateMessage(t, buildValidatePlugin(t, tt.file), "Record")
+			for _, field := range msg.Fields {
+				name := string(field.Desc.Name())
+				if got := HasExplicitPresence(field); got != tt.want[name] {
+					t.Errorf("HasExplicitPresence(%s) = %v, want %v", name, got, tt.want[name])
+				}
+			}
+		})
+	}
+}
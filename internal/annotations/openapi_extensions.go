@@ -0,0 +1,54 @@
+package annotations
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+// OpenAPIExtension is a vendor extension to copy into the generated OpenAPI document.
+type OpenAPIExtension struct {
+	Name  string // Always starts with "x-"
+	Value string // JSON
+}
+
+// GetOpenAPIExtensions returns the vendor extensions for a method's OpenAPI operation:
+// the service's extensions followed by the method's, where a method extension replaces
+// a service extension of the same name. It returns an error for a name that does not
+// start with "x-" or a value that is not valid JSON.
+func GetOpenAPIExtensions(service *protogen.Service, method *protogen.Method) ([]OpenAPIExtension, error) {
+	var extensions []OpenAPIExtension
+	index := make(map[string]int)
+
+	add := func(configs []*http.OpenAPIExtension) error {
+		for _, config := range configs {
+			extension := OpenAPIExtension{Name: config.GetName(), Value: config.GetValue()}
+			if !strings.HasPrefix(extension.Name, "x-") {
+				return fmt.Errorf("OpenAPI extension name %q must start with \"x-\"", extension.Name)
+			}
+			if !json.Valid([]byte(extension.Value)) {
+				return fmt.Errorf("OpenAPI extension %s has a value that is not valid JSON: %s",
+					extension.Name, extension.Value)
+			}
+			if i, ok := index[extension.Name]; ok {
+				extensions[i] = extension
+				continue
+			}
+			index[extension.Name] = len(extensions)
+			extensions = append(extensions, extension)
+		}
+		return nil
+	}
+
+	if err := add(getServiceConfig(service).GetOpenapiExtensions()); err != nil {
+		return nil, err
+	}
+	if err := add(getMethodConfig(method).GetOpenapiExtensions()); err != nil {
+		return nil, err
+	}
+	return extensions, nil
+}
@@ -25,6 +25,27 @@ func ExtractPathParams(path string) []string {
 	return params
 }
 
+// SplitCustomVerb splits the AIP-136 custom verb off a path whose last segment is a path
+// variable followed by ":verb".
+// Example: "/users/{id}:activate" -> "/users/{id}", "activate".
+// Other paths, including a verb after a literal segment ("/users:batchGet"), are returned
+// unchanged with an empty verb.
+func SplitCustomVerb(path string) (string, string) {
+	segment := path[strings.LastIndex(path, "/")+1:]
+	if !strings.HasPrefix(segment, "{") {
+		return path, ""
+	}
+	closing := strings.Index(segment, "}")
+	if closing < 0 || !strings.HasPrefix(segment[closing+1:], ":") {
+		return path, ""
+	}
+	verb := segment[closing+2:]
+	if verb == "" || strings.ContainsAny(verb, "{}") {
+		return path, ""
+	}
+	return strings.TrimSuffix(path, ":"+verb), verb
+}
+
 // BuildHTTPPath combines service base path with method path.
 // Handles slash normalization between the two path segments.
 func BuildHTTPPath(servicePath, methodPath string) string {
@@ -0,0 +1,108 @@
+package annotations
+
+import (
+	"fmt"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// HTTP status codes accepted in a responses entry.
+const (
+	minResponseStatus = 100
+	maxResponseStatus = 599
+)
+
+// MethodResponse is an additional response documented for a method through
+// HttpConfig.responses.
+type MethodResponse struct {
+	Status      int
+	Description string
+	// Message is the fully-qualified name of the response body message, or empty for a
+	// response without a body.
+	Message protoreflect.FullName
+}
+
+// GetMethodResponses extracts the additional responses declared on a method. It returns
+// an error for a status code outside 100-599, a status listed twice, or a message that
+// is not defined in the method's file or one of its imports.
+func GetMethodResponses(method *protogen.Method) ([]MethodResponse, error) {
+	configs := getMethodConfig(method).GetResponses()
+	if len(configs) == 0 {
+		return nil, nil
+	}
+
+	responses := make([]MethodResponse, 0, len(configs))
+	seen := make(map[int]bool, len(configs))
+	for _, config := range configs {
+		status := int(config.GetStatus())
+		if status < minResponseStatus || status > maxResponseStatus {
+			return nil, fmt.Errorf("response status %d is not a valid HTTP status code", status)
+		}
+		if seen[status] {
+			return nil, fmt.Errorf("response status %d is listed more than once", status)
+		}
+		seen[status] = true
+
+		name := protoreflect.FullName(strings.TrimPrefix(config.GetMessage(), "."))
+		if name != "" && findMessageDescriptor(method.Desc.ParentFile(), name, map[string]bool{}) == nil {
+			return nil, fmt.Errorf(
+				"response %d references message %q, which is not defined in %s or its imports",
+				status, name, method.Desc.ParentFile().Path())
+		}
+
+		responses = append(responses, MethodResponse{
+			Status:      status,
+			Description: config.GetDescription(),
+			Message:     name,
+		})
+	}
+	return responses, nil
+}
+
+// findMessageDescriptor looks name up in file and, transitively, in its imports.
+func findMessageDescriptor(
+	file protoreflect.FileDescriptor,
+	name protoreflect.FullName,
+	visited map[string]bool,
+) protoreflect.MessageDescriptor {
+	if visited[file.Path()] {
+		return nil
+	}
+	visited[file.Path()] = true
+
+	if desc := lookupMessage(file, name); desc != nil {
+		return desc
+	}
+
+	imports := file.Imports()
+	for i := range imports.Len() {
+		if desc := findMessageDescriptor(imports.Get(i).FileDescriptor, name, visited); desc != nil {
+			return desc
+		}
+	}
+	return nil
+}
+
+// lookupMessage finds a top-level or nested message of file by its full name.
+func lookupMessage(file protoreflect.FileDescriptor, name protoreflect.FullName) protoreflect.MessageDescriptor {
+	relative := string(name)
+	if pkg := string(file.Package()); pkg != "" {
+		var ok bool
+		if relative, ok = strings.CutPrefix(relative, pkg+"."); !ok {
+			return nil
+		}
+	}
+
+	messages := file.Messages()
+	var desc protoreflect.MessageDescriptor
+	for _, part := range strings.Split(relative, ".") {
+		desc = messages.ByName(protoreflect.Name(part))
+		if desc == nil {
+			return nil
+		}
+		messages = desc.Messages()
+	}
+	return desc
+}
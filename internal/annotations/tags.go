@@ -0,0 +1,46 @@
+package annotations
+
+import (
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// OpenAPITag is a tag declared in a service's ServiceConfig.tags.
+type OpenAPITag struct {
+	Name            string
+	Description     string
+	ExternalDocsURL string
+}
+
+// GetServiceTags returns the tags declared on a service, or nil if it declares none.
+func GetServiceTags(service *protogen.Service) []OpenAPITag {
+	configs := getServiceConfig(service).GetTags()
+	if len(configs) == 0 {
+		return nil
+	}
+
+	tags := make([]OpenAPITag, 0, len(configs))
+	for _, config := range configs {
+		tags = append(tags, OpenAPITag{
+			Name:            config.GetName(),
+			Description:     config.GetDescription(),
+			ExternalDocsURL: config.GetExternalDocsUrl(),
+		})
+	}
+	return tags
+}
+
+// GetOperationTags returns the tag names of a method's OpenAPI operation: the method's
+// tags if it has any, otherwise the service's tags, otherwise the service name.
+func GetOperationTags(service *protogen.Service, method *protogen.Method) []string {
+	if tags := getMethodConfig(method).GetTags(); len(tags) > 0 {
+		return tags
+	}
+	if serviceTags := GetServiceTags(service); len(serviceTags) > 0 {
+		names := make([]string, 0, len(serviceTags))
+		for _, tag := range serviceTags {
+			names = append(names, tag.Name)
+		}
+		return names
+	}
+	return []string{string(service.Desc.Name())}
+}
@@ -0,0 +1,86 @@
+package annotations
+
+import (
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// JSON Schema types of the well-known types' ProtoJSON forms.
+const (
+	JSONTypeString  = "string"
+	JSONTypeNumber  = "number"
+	JSONTypeInteger = "integer"
+	JSONTypeBoolean = "boolean"
+	JSONTypeObject  = "object"
+	JSONTypeArray   = "array"
+)
+
+// WellKnownType describes the ProtoJSON form of a google.protobuf well-known type. Most
+// of them do not serialize as the message protoc describes: a Duration is "1.5s", not
+// {"seconds": 1, "nanos": 500000000}, and an Int32Value is a bare number.
+type WellKnownType struct {
+	// JSONType is the JSON Schema type of the value, one of the JSONType constants. It
+	// is empty for google.protobuf.Value, which can be any JSON value.
+	JSONType string
+	// Format is the OpenAPI format of the value, if any.
+	Format string
+	// Pattern constrains string values, if any.
+	Pattern string
+	// Description explains the encoding when the type alone does not.
+	Description string
+	// Int64 is set for the 64-bit wrappers, which follow int64_encoding like int64 fields:
+	// a string by default, a number with int64_encoding=NUMBER.
+	Int64 bool
+}
+
+//nolint:gochecknoglobals // read-only lookup table
+var wellKnownTypes = map[protoreflect.FullName]WellKnownType{
+	"google.protobuf.Timestamp": {JSONType: JSONTypeString, Format: "date-time"},
+	"google.protobuf.Duration": {
+		JSONType:    JSONTypeString,
+		Pattern:     `^-?[0-9]+(\.[0-9]{1,9})?s$`,
+		Description: `Duration in seconds with up to nine fractional digits, e.g. "1.5s"`,
+	},
+	"google.protobuf.FieldMask": {
+		JSONType:    JSONTypeString,
+		Description: `Comma-separated lowerCamelCase field paths, e.g. "name,address.city"`,
+	},
+	"google.protobuf.Any": {
+		JSONType:    JSONTypeObject,
+		Description: `A message of any type, named by its "@type" URL`,
+	},
+	"google.protobuf.Empty":       {JSONType: JSONTypeObject},
+	"google.protobuf.Struct":      {JSONType: JSONTypeObject},
+	"google.protobuf.Value":       {},
+	"google.protobuf.ListValue":   {JSONType: JSONTypeArray},
+	"google.protobuf.StringValue": {JSONType: JSONTypeString},
+	"google.protobuf.BytesValue":  {JSONType: JSONTypeString, Format: "byte"},
+	"google.protobuf.BoolValue":   {JSONType: JSONTypeBoolean},
+	"google.protobuf.Int32Value":  {JSONType: JSONTypeInteger, Format: "int32"},
+	"google.protobuf.UInt32Value": {JSONType: JSONTypeInteger, Format: "int32"},
+	"google.protobuf.Int64Value":  {JSONType: JSONTypeString, Format: "int64", Int64: true},
+	"google.protobuf.UInt64Value": {JSONType: JSONTypeString, Format: "uint64", Int64: true},
+	"google.protobuf.FloatValue":  {JSONType: JSONTypeNumber, Format: "float"},
+	"google.protobuf.DoubleValue": {JSONType: JSONTypeNumber, Format: "double"},
+}
+
+// GetWellKnownType returns the ProtoJSON form of message if it is a google.protobuf
+// well-known type with a JSON mapping of its own. Generators should describe such a
+// message field by this form instead of by the message's fields.
+func GetWellKnownType(message *protogen.Message) (WellKnownType, bool) {
+	if message == nil {
+		return WellKnownType{}, false
+	}
+	wkt, ok := wellKnownTypes[message.Desc.FullName()]
+	return wkt, ok
+}
+
+// IsWellKnownField returns true if the field is a singular or repeated message field of a
+// well-known type listed by GetWellKnownType. Map fields are not.
+func IsWellKnownField(field *protogen.Field) bool {
+	if field.Desc.Kind() != protoreflect.MessageKind || field.Desc.IsMap() {
+		return false
+	}
+	_, ok := GetWellKnownType(field.Message)
+	return ok
+}
@@ -0,0 +1,41 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestServiceAPIVersions(t *testing.T) {
+	noteConfig := &http.HttpConfig{Path: "/notes", Method: http.HttpMethod_HTTP_METHOD_POST}
+
+	t.Run("versioned service wraps every handler", func(t *testing.T) {
+		file := noteServiceFile(noteConfig)
+		file.Service[0].Options = &descriptorpb.ServiceOptions{}
+		proto.SetExtension(file.Service[0].Options, http.E_ServiceConfig, &http.ServiceConfig{
+			ApiVersions: []string{"2024-01-01", "2025-06-01"},
+		})
+		httpFile := generateFromDescriptor(t, Options{}, file, "_http.pb.go")
+
+		for _, want := range []string{
+			`apiVersions := []string{"2024-01-01", "2025-06-01"}`,
+			"createNoteHandler = sebufhttp.RequireAPIVersion(apiVersions, config.writeError, createNoteHandler)",
+		} {
+			if !strings.Contains(httpFile, want) {
+				t.Errorf("generated file missing %q:\n%s", want, httpFile)
+			}
+		}
+	})
+
+	t.Run("unversioned service is not wrapped", func(t *testing.T) {
+		httpFile := generateNoteService(t, Options{}, noteConfig, "_http.pb.go")
+
+		if strings.Contains(httpFile, "RequireAPIVersion") {
+			t.Errorf("did not expect API version enforcement:\n%s", httpFile)
+		}
+	})
+}
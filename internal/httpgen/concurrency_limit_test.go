@@ -0,0 +1,52 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestMaxConcurrentRequests(t *testing.T) {
+	const wrap = "createNoteHandler = sebufhttp.ConcurrencyLimit("
+
+	t.Run("limited method is wrapped", func(t *testing.T) {
+		httpFile := generateNoteService(t, Options{}, &http.HttpConfig{
+			Path:                  "/notes",
+			Method:                http.HttpMethod_HTTP_METHOD_POST,
+			MaxConcurrentRequests: 4,
+		}, "_http.pb.go")
+
+		if !strings.Contains(httpFile, wrap+"4, config.writeError, createNoteHandler)") {
+			t.Errorf("expected CreateNote handler to be wrapped with a limit of 4:\n%s", httpFile)
+		}
+	})
+
+	t.Run("rejections go through the error handler", func(t *testing.T) {
+		bindingFile := generateNoteService(t, Options{}, &http.HttpConfig{
+			Path:                  "/notes",
+			Method:                http.HttpMethod_HTTP_METHOD_POST,
+			MaxConcurrentRequests: 4,
+		}, "_http_binding.pb.go")
+
+		for _, want := range []string{
+			"func (c *serverConfiguration) writeError(w http.ResponseWriter, r *http.Request, statusCode int, err error) {",
+			"writeErrorWithStatus(w, r, err, statusCode, c.errorHandler, c.marshalOpts)",
+		} {
+			if !strings.Contains(bindingFile, want) {
+				t.Errorf("expected binding file to contain %q:\n%s", want, bindingFile)
+			}
+		}
+	})
+
+	t.Run("unlimited method is not wrapped", func(t *testing.T) {
+		httpFile := generateNoteService(t, Options{}, &http.HttpConfig{
+			Path:   "/notes",
+			Method: http.HttpMethod_HTTP_METHOD_POST,
+		}, "_http.pb.go")
+
+		if strings.Contains(httpFile, wrap) {
+			t.Errorf("did not expect a concurrency limit:\n%s", httpFile)
+		}
+	})
+}
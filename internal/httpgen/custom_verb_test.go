@@ -0,0 +1,45 @@
+package httpgen
+
+import (
+	"regexp"
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestCustomVerbRegistration(t *testing.T) {
+	file := noteServiceFile(&http.HttpConfig{Path: "/notes/{text}:publish", Method: http.HttpMethod_HTTP_METHOD_POST})
+	updateOptions := &descriptorpb.MethodOptions{}
+	proto.SetExtension(updateOptions, http.E_Config, &http.HttpConfig{
+		Path:   "/notes/{text}",
+		Method: http.HttpMethod_HTTP_METHOD_POST,
+	})
+	file.Service[0].Method = append(file.Service[0].Method, &descriptorpb.MethodDescriptorProto{
+		Name:       proto.String("UpdateNote"),
+		InputType:  proto.String(".test.notes.v1.CreateNoteRequest"),
+		OutputType: proto.String(".test.notes.v1.Note"),
+		Options:    updateOptions,
+	})
+
+	httpFile := generateFromDescriptor(t, Options{}, file, "_http.pb.go")
+
+	for _, want := range []*regexp.Regexp{
+		regexp.MustCompile(regexp.QuoteMeta(
+			`config.mux.Handle("POST /notes/{text}", sebufhttp.CustomVerbs("text", sebufhttp.VerbHandlers{`,
+		)),
+		regexp.MustCompile(`"publish":\s+createNoteHandler,`),
+		regexp.MustCompile(`"":\s+updateNoteHandler,`),
+	} {
+		if !want.MatchString(httpFile) {
+			t.Errorf("generated file does not match %s:\n%s", want, httpFile)
+		}
+	}
+	if strings.Contains(httpFile, `config.mux.Handle("POST /notes/{text}", updateNoteHandler)`) ||
+		strings.Contains(httpFile, ":publish") {
+		t.Errorf("custom verb methods should only be registered through CustomVerbs:\n%s", httpFile)
+	}
+}
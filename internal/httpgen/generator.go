@@ -17,17 +17,28 @@ type Generator struct {
 	generateMock bool
 	globalUnwrap *GlobalUnwrapInfo // Global unwrap info collected from all files
 
+	// discardUnknownFields makes JSON request binding ignore unknown keys instead of
+	// rejecting the request (unknown_fields=ignore).
+	discardUnknownFields bool
+
 	// directEncodingMsgNames is set per-file before generateUnwrapFile runs.
 	// It holds the full names of messages that will have custom MarshalJSON/UnmarshalJSON
 	// from the encoding generator (direct int64_encoding=NUMBER fields).
 	// The unwrap generator uses this to call json.Marshal instead of protojson.Marshal
 	// for those types, ensuring the custom encoding is applied.
 	directEncodingMsgNames map[string]bool
+
+	// checkRequiredFields is set per-file in generateBindingFile. It is true when a request
+	// message of the file uses proto2 required fields, enabling the required-field check.
+	checkRequiredFields bool
 }
 
 // Options configures the generator.
 type Options struct {
 	GenerateMock bool
+	// DiscardUnknownFields makes generated handlers ignore unknown JSON keys in request
+	// bodies. By default they are rejected with a 400 validation error.
+	DiscardUnknownFields bool
 }
 
 // New creates a new HTTP generator.
@@ -40,8 +51,9 @@ func New(plugin *protogen.Plugin) *Generator {
 // NewWithOptions creates a new HTTP generator with options.
 func NewWithOptions(plugin *protogen.Plugin, opts Options) *Generator {
 	return &Generator{
-		plugin:       plugin,
-		generateMock: opts.GenerateMock,
+		plugin:               plugin,
+		generateMock:         opts.GenerateMock,
+		discardUnknownFields: opts.DiscardUnknownFields,
 	}
 }
 
@@ -236,6 +248,14 @@ func (g *Generator) generateService(gf *protogen.GeneratedFile, file *protogen.F
 	gf.P("serviceHeaders := get", serviceName, "Headers()")
 	gf.P()
 
+	apiVersions := annotations.GetServiceAPIVersions(service)
+	if len(apiVersions) > 0 {
+		gf.P("apiVersions := ", fmt.Sprintf("%#v", apiVersions))
+		gf.P()
+	}
+
+	verbRoutes, dispatched := g.collectCustomVerbRoutes(service, basePath, file.GoPackageName)
+
 	for i, method := range service.Methods {
 		httpPath := g.getMethodPath(method, basePath, file.GoPackageName)
 		httpMethod := g.getHTTPMethod(method)
@@ -247,40 +267,22 @@ func (g *Generator) generateService(gf *protogen.GeneratedFile, file *protogen.F
 			gf.P("methodHeaders = get", method.GoName, "Headers()")
 		}
 
-		if g.isSSEMethod(method) {
-			// SSE handler registration
-			gf.P(handlerName, " := SSEHandler[", method.Input.GoIdent, "](")
-			gf.P("server.", method.GoName, ", config.errorHandler, serviceHeaders, methodHeaders,")
-			gf.P(
-				annotations.LowerFirst(method.GoName),
-				"PathParams, ",
-				annotations.LowerFirst(method.GoName),
-				"QueryParams,",
-			)
-			gf.P(`"`, httpMethod, `", config.marshalOpts,`)
-			gf.P(")")
-		} else {
-			// Standard handler registration
-			gf.P(handlerName, " := BindingMiddleware[", method.Input.GoIdent, "](")
-			gf.P(
-				"genericHandler(server.",
-				method.GoName,
-				", config.errorHandler, config.marshalOpts), serviceHeaders, methodHeaders,",
-			)
-			gf.P(
-				annotations.LowerFirst(method.GoName),
-				"PathParams, ",
-				annotations.LowerFirst(method.GoName),
-				"QueryParams,",
-			)
-			gf.P(`"`, httpMethod, `", config.errorHandler, config.marshalOpts,`)
-			gf.P(")")
+		g.generateHandlerConstruction(gf, method, handlerName, httpMethod)
+		if limit := g.getMaxConcurrentRequests(method); limit > 0 {
+			gf.P(handlerName, " = sebufhttp.ConcurrencyLimit(", limit, ", config.writeError, ", handlerName, ")")
+		}
+		if len(apiVersions) > 0 {
+			gf.P(handlerName, " = sebufhttp.RequireAPIVersion(apiVersions, config.writeError, ", handlerName, ")")
 		}
 		gf.P()
-		gf.P(`config.mux.Handle("`, httpMethod, ` `, httpPath, `", `, handlerName, `)`)
-		gf.P()
+		if pattern, _ := g.getMethodPattern(method, basePath, file.GoPackageName); !dispatched[pattern] {
+			gf.P(`config.mux.Handle("`, httpMethod, ` `, httpPath, `", `, handlerName, `)`)
+			gf.P()
+		}
 	}
 
+	g.generateCustomVerbRoutes(gf, verbRoutes)
+
 	gf.P("return nil")
 	gf.P("}")
 	gf.P()
@@ -298,12 +300,121 @@ func (g *Generator) generateService(gf *protogen.GeneratedFile, file *protogen.F
 	return nil
 }
 
+// customVerbRoute is a ServeMux pattern shared by methods whose paths end with an AIP-136
+// custom verb ("/users/{id}:activate" is registered as "/users/{id}"). The methods are
+// registered together behind sebufhttp.CustomVerbs, which dispatches on the verb.
+type customVerbRoute struct {
+	pattern  string   // ServeMux pattern, e.g. "POST /users/{id}"
+	param    string   // path variable the verb follows
+	verbs    []string // verb of each method; "" for a method without one
+	handlers []string // handler variable of each method
+}
+
+// collectCustomVerbRoutes returns, in method order, the ServeMux patterns of the service
+// that at least one custom verb method is registered under, with every method sharing
+// them, and the set of those patterns.
+func (g *Generator) collectCustomVerbRoutes(
+	service *protogen.Service,
+	basePath string,
+	packageName protogen.GoPackageName,
+) ([]*customVerbRoute, map[string]bool) {
+	var routes []*customVerbRoute
+	byPattern := make(map[string]*customVerbRoute)
+	hasVerb := make(map[string]bool)
+	for _, method := range service.Methods {
+		pattern, verb := g.getMethodPattern(method, basePath, packageName)
+		route := byPattern[pattern]
+		if route == nil {
+			route = &customVerbRoute{pattern: pattern}
+			if params := annotations.ExtractPathParams(pattern); len(params) > 0 {
+				route.param = params[len(params)-1]
+			}
+			byPattern[pattern] = route
+			routes = append(routes, route)
+		}
+		route.verbs = append(route.verbs, verb)
+		route.handlers = append(route.handlers, annotations.LowerFirst(method.GoName)+"Handler")
+		hasVerb[pattern] = hasVerb[pattern] || verb != ""
+	}
+
+	verbRoutes := routes[:0]
+	for _, route := range routes {
+		if hasVerb[route.pattern] {
+			verbRoutes = append(verbRoutes, route)
+		}
+	}
+	return verbRoutes, hasVerb
+}
+
+// generateCustomVerbRoutes registers each custom verb route behind sebufhttp.CustomVerbs.
+func (g *Generator) generateCustomVerbRoutes(gf *protogen.GeneratedFile, routes []*customVerbRoute) {
+	for _, route := range routes {
+		gf.P(`config.mux.Handle("`, route.pattern, `", sebufhttp.CustomVerbs("`, route.param,
+			`", sebufhttp.VerbHandlers{`)
+		for i, verb := range route.verbs {
+			gf.P(`"`, verb, `": `, route.handlers[i], `,`)
+		}
+		gf.P("}))")
+		gf.P()
+	}
+}
+
+// getMethodPattern returns the ServeMux pattern a method is registered under, and the
+// custom verb its path ends with, if any.
+func (g *Generator) getMethodPattern(
+	method *protogen.Method,
+	basePath string,
+	packageName protogen.GoPackageName,
+) (string, string) {
+	path, verb := annotations.SplitCustomVerb(g.getMethodPath(method, basePath, packageName))
+	return g.getHTTPMethod(method) + " " + path, verb
+}
+
+// generateHandlerConstruction emits the handler variable for a method: an SSEHandler for
+// streaming methods, otherwise BindingMiddleware around genericHandler.
+func (g *Generator) generateHandlerConstruction(
+	gf *protogen.GeneratedFile,
+	method *protogen.Method,
+	handlerName, httpMethod string,
+) {
+	if g.isSSEMethod(method) {
+		// SSE handler registration
+		gf.P(handlerName, " := SSEHandler[", method.Input.GoIdent, "](")
+		gf.P("server.", method.GoName, ", config.errorHandler, serviceHeaders, methodHeaders,")
+		gf.P(
+			annotations.LowerFirst(method.GoName),
+			"PathParams, ",
+			annotations.LowerFirst(method.GoName),
+			"QueryParams,",
+		)
+		gf.P(`"`, httpMethod, `", config.marshalOpts,`)
+		gf.P(")")
+	} else {
+		// Standard handler registration
+		gf.P(handlerName, " := BindingMiddleware[", method.Input.GoIdent, "](")
+		gf.P(
+			"genericHandler(server.",
+			method.GoName,
+			", config.errorHandler, config.marshalOpts), serviceHeaders, methodHeaders,",
+		)
+		gf.P(
+			annotations.LowerFirst(method.GoName),
+			"PathParams, ",
+			annotations.LowerFirst(method.GoName),
+			"QueryParams,",
+		)
+		gf.P(`"`, httpMethod, `", config.errorHandler, config.marshalOpts,`)
+		gf.P(")")
+	}
+}
+
 //nolint:funlen // This function generates a lot of boilerplate code
 func (g *Generator) generateBindingFile(file *protogen.File) error {
 	filename := file.GeneratedFilenamePrefix + "_http_binding.pb.go"
 	gf := g.plugin.NewGeneratedFile(filename, file.GoImportPath)
 
 	g.writeHeader(gf, file)
+	g.checkRequiredFields = fileHasRequiredRequestFields(file)
 
 	gf.P("import (")
 	gf.P(`"bytes"`)
@@ -433,6 +544,9 @@ func (g *Generator) generateBindingFile(file *protogen.File) error {
 	gf.P("}")
 	gf.P("}")
 	gf.P()
+	if g.checkRequiredFields {
+		g.generateRequiredFieldsCheck(gf, "toBind")
+	}
 	gf.P("// Validate the complete message")
 	gf.P("if msg, ok := any(toBind).(proto.Message); ok {")
 	gf.P("if err := ValidateMessage(msg); err != nil {")
@@ -512,6 +626,17 @@ func (g *Generator) generateBindingFile(file *protogen.File) error {
 	gf.P("return nil")
 	gf.P("}")
 	gf.P()
+	if g.discardUnknownFields {
+		gf.P("opts := protojson.UnmarshalOptions{DiscardUnknown: true}")
+		gf.P()
+		gf.P("// Custom unmarshalers that accept options honor the unknown-field policy")
+		gf.P(
+			"if u, ok := any(toBind).(interface{ UnmarshalJSONSebuf([]byte, protojson.UnmarshalOptions) error }); ok {",
+		)
+		gf.P("return u.UnmarshalJSONSebuf(bodyBytes, opts)")
+		gf.P("}")
+		gf.P()
+	}
 	gf.P("// Check for custom JSON unmarshaler (unwrap support)")
 	gf.P("if unmarshaler, ok := any(toBind).(json.Unmarshaler); ok {")
 	gf.P("return unmarshaler.UnmarshalJSON(bodyBytes)")
@@ -522,7 +647,11 @@ func (g *Generator) generateBindingFile(file *protogen.File) error {
 	gf.P(`return errors.New("JSON request is not a protocol buffer message")`)
 	gf.P("}")
 	gf.P()
-	gf.P("err = protojson.Unmarshal(bodyBytes, protoRequest)")
+	if g.discardUnknownFields {
+		gf.P("err = opts.Unmarshal(bodyBytes, protoRequest)")
+	} else {
+		gf.P("err = protojson.Unmarshal(bodyBytes, protoRequest)")
+	}
 	gf.P("if err != nil {")
 	gf.P(`return fmt.Errorf("could not unmarshal request JSON: %w", err)`)
 	gf.P("}")
@@ -832,6 +961,11 @@ func (g *Generator) generateBindingFile(file *protogen.File) error {
 	// Generate header validation support
 	g.generateHeaderValidationFunctions(gf)
 
+	// Generate proto2 required-field support if any request message needs it
+	if g.checkRequiredFields {
+		g.generateRequiredFieldViolationsFunc(gf)
+	}
+
 	// Generate SSE support if any service has SSE methods
 	for _, service := range file.Services {
 		if g.serviceHasSSEMethods(service) {
@@ -1025,6 +1159,15 @@ func (g *Generator) isSSEMethod(method *protogen.Method) bool {
 	return config != nil && config.Stream
 }
 
+// getMaxConcurrentRequests returns the method's concurrent request limit, or 0 if unlimited.
+func (g *Generator) getMaxConcurrentRequests(method *protogen.Method) uint32 {
+	config := annotations.GetMethodHTTPConfig(method)
+	if config != nil {
+		return config.MaxConcurrentRequests
+	}
+	return 0
+}
+
 // serviceHasSSEMethods checks if any method in the service uses SSE streaming.
 func (g *Generator) serviceHasSSEMethods(service *protogen.Service) bool {
 	for _, method := range service.Methods {
@@ -1214,12 +1357,29 @@ func (g *Generator) generateDefaultErrorStatusCodeFunc(gf *protogen.GeneratedFil
 	gf.P()
 }
 
-// generateWriteErrorWithHandlerFunc generates the writeErrorWithHandler function.
+// generateWriteErrorWithHandlerFunc generates the writeErrorWithHandler and
+// writeErrorWithStatus functions, and the serverConfiguration.writeError method the
+// sebufhttp wrappers render their rejections with.
 func (g *Generator) generateWriteErrorWithHandlerFunc(gf *protogen.GeneratedFile) {
 	gf.P("// writeErrorWithHandler calls custom handler if set, then marshals response")
 	gf.P(
 		"func writeErrorWithHandler(w http.ResponseWriter, r *http.Request, err error, handler ErrorHandler, marshalOpts protojson.MarshalOptions) {",
 	)
+	gf.P("writeErrorWithStatus(w, r, err, defaultErrorStatusCode(err), handler, marshalOpts)")
+	gf.P("}")
+	gf.P()
+	gf.P("// writeError writes err with statusCode through the configured error handler and marshal")
+	gf.P("// options. It is the sebufhttp.ErrorWriter passed to the sebufhttp handler wrappers.")
+	gf.P(
+		"func (c *serverConfiguration) writeError(w http.ResponseWriter, r *http.Request, statusCode int, err error) {",
+	)
+	gf.P("writeErrorWithStatus(w, r, err, statusCode, c.errorHandler, c.marshalOpts)")
+	gf.P("}")
+	gf.P()
+	gf.P("// writeErrorWithStatus calls custom handler if set, then marshals response with statusCode")
+	gf.P(
+		"func writeErrorWithStatus(w http.ResponseWriter, r *http.Request, err error, statusCode int, handler ErrorHandler, marshalOpts protojson.MarshalOptions) {",
+	)
 	gf.P("var response proto.Message")
 	gf.P("var capture *responseCapture")
 	gf.P()
@@ -1236,9 +1396,6 @@ func (g *Generator) generateWriteErrorWithHandlerFunc(gf *protogen.GeneratedFile
 	gf.P("response = defaultErrorResponse(err)")
 	gf.P("}")
 	gf.P()
-	gf.P("// Determine status code")
-	gf.P("statusCode := defaultErrorStatusCode(err)")
-	gf.P()
 	gf.P("// If handler already set status, don't set it again")
 	gf.P("if capture != nil && capture.wroteHeader {")
 	gf.P("// Handler set status, just write the body")
@@ -1827,6 +1984,10 @@ func (g *Generator) generateSSETypes(gf *protogen.GeneratedFile) {
 	gf.P("}")
 	gf.P()
 
+	if g.checkRequiredFields {
+		g.generateRequiredFieldsCheck(gf, "req")
+	}
+
 	// Validate request body
 	gf.P("// Validate request body")
 	gf.P("if msg, ok := any(req).(proto.Message); ok {")
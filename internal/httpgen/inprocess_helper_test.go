@@ -0,0 +1,91 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/pluginpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+// generateNoteService runs the generator in-process on a single-RPC NoteService whose
+// CreateNote method carries the given HTTP config, and returns the content of the emitted
+// file ending in suffix (e.g. "_http_binding.pb.go").
+func generateNoteService(t *testing.T, opts Options, config *http.HttpConfig, suffix string) string {
+	t.Helper()
+	return generateFromDescriptor(t, opts, noteServiceFile(config), suffix)
+}
+
+// noteServiceFile builds notes.proto: a NoteService whose CreateNote method carries config.
+func noteServiceFile(config *http.HttpConfig) *descriptorpb.FileDescriptorProto {
+	methodOptions := &descriptorpb.MethodOptions{}
+	proto.SetExtension(methodOptions, http.E_Config, config)
+	return &descriptorpb.FileDescriptorProto{
+		Name:    proto.String("notes.proto"),
+		Package: proto.String("test.notes.v1"),
+		Syntax:  proto.String("proto3"),
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("github.com/SebastienMelki/sebuf/internal/httpgen/testnotes"),
+		},
+		MessageType: []*descriptorpb.DescriptorProto{
+			{
+				Name: proto.String("CreateNoteRequest"),
+				Field: []*descriptorpb.FieldDescriptorProto{{
+					Name:     proto.String("text"),
+					Number:   proto.Int32(1),
+					Label:    descriptorpb.FieldDescriptorProto_LABEL_OPTIONAL.Enum(),
+					Type:     descriptorpb.FieldDescriptorProto_TYPE_STRING.Enum(),
+					JsonName: proto.String("text"),
+				}},
+			},
+			{Name: proto.String("Note")},
+		},
+		Service: []*descriptorpb.ServiceDescriptorProto{{
+			Name: proto.String("NoteService"),
+			Method: []*descriptorpb.MethodDescriptorProto{{
+				Name:       proto.String("CreateNote"),
+				InputType:  proto.String(".test.notes.v1.CreateNoteRequest"),
+				OutputType: proto.String(".test.notes.v1.Note"),
+				Options:    methodOptions,
+			}},
+		}},
+	}
+}
+
+// generateFromDescriptor runs the generator in-process on a hand-built file descriptor and
+// returns the content of the emitted file ending in suffix.
+func generateFromDescriptor(
+	t *testing.T,
+	opts Options,
+	file *descriptorpb.FileDescriptorProto,
+	suffix string,
+) string {
+	t.Helper()
+
+	plugin, err := protogen.Options{}.New(&pluginpb.CodeGeneratorRequest{
+		FileToGenerate: []string{file.GetName()},
+		ProtoFile:      []*descriptorpb.FileDescriptorProto{file},
+	})
+	if err != nil {
+		t.Fatalf("protogen.Options{}.New: %v", err)
+	}
+	if err = NewWithOptions(plugin, opts).Generate(); err != nil {
+		t.Fatalf("Generate() failed: %v", err)
+	}
+
+	resp := plugin.Response()
+	if resp.Error != nil {
+		t.Fatalf("plugin response error: %s", resp.GetError())
+	}
+	for _, f := range resp.GetFile() {
+		if strings.HasSuffix(f.GetName(), suffix) {
+			return f.GetContent()
+		}
+	}
+	t.Fatalf("no *%s file generated", suffix)
+	return ""
+}
@@ -0,0 +1,67 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/pluginpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+func TestValidateMethodResponses(t *testing.T) {
+	tests := []struct {
+		name      string
+		responses []*http.ResponseConfig
+		wantErr   string
+	}{
+		{
+			name:      "valid responses",
+			responses: []*http.ResponseConfig{
+				{Status: 404, Description: "Note not found", Message: "test.notes.v1.Note"},
+				{Status: 409},
+			},
+		},
+		{
+			name:      "status out of range",
+			responses: []*http.ResponseConfig{{Status: 42}},
+			wantErr:   "response status 42 is not a valid HTTP status code",
+		},
+		{
+			name:      "duplicate status",
+			responses: []*http.ResponseConfig{{Status: 404}, {Status: 404}},
+			wantErr:   "response status 404 is listed more than once",
+		},
+		{
+			name:      "unknown message",
+			responses: []*http.ResponseConfig{{Status: 404, Message: "test.notes.v1.Missing"}},
+			wantErr:   `response 404 references message "test.notes.v1.Missing"`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			file := noteServiceFile(&http.HttpConfig{Path: "/notes", Responses: tt.responses})
+			plugin, err := protogen.Options{}.New(&pluginpb.CodeGeneratorRequest{
+				FileToGenerate: []string{file.GetName()},
+				ProtoFile:      []*descriptorpb.FileDescriptorProto{file},
+			})
+			if err != nil {
+				t.Fatalf("protogen.Options{}.New: %v", err)
+			}
+
+			err = ValidateService(plugin.Files[0].Services[0])
+			if tt.wantErr == "" {
+				if err != nil {
+					t.Fatalf("ValidateService() = %v, want nil", err)
+				}
+				return
+			}
+			if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+				t.Fatalf("ValidateService() = %v, want error containing %q", err, tt.wantErr)
+			}
+		})
+	}
+}
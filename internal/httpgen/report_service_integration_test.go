@@ -0,0 +1,276 @@
+package httpgen
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+)
+
+// TestReportServiceIntegration is an end-to-end integration test that:
+//  1. generates Go HTTP server code from report_service.proto using the freshly built plugin,
+//  2. writes a temporary Go module that registers the generated handlers on a ServeMux,
+//  3. sends real requests through the sebufhttp runtime to verify proto2 required-field
+//     checks, custom verb routing, the concurrency limit and API version enforcement.
+//
+// The string tests next to each generator change only inspect the emitted source; this one
+// compiles it against the http package and checks the responses, including that wrapper
+// rejections go through the configured ErrorHandler and content negotiation.
+func TestReportServiceIntegration(t *testing.T) {
+	if _, err := exec.LookPath("protoc"); err != nil {
+		t.Skip("protoc not found, skipping integration test")
+	}
+
+	baseDir, err := os.Getwd()
+	if err != nil {
+		t.Fatalf("Failed to get working directory: %v", err)
+	}
+
+	projectRoot := filepath.Join(baseDir, "..", "..")
+	protoDir := filepath.Join(baseDir, "testdata", "proto")
+	pluginPath := filepath.Join(projectRoot, "bin", "protoc-gen-go-http")
+
+	if _, statErr := os.Stat(pluginPath); os.IsNotExist(statErr) {
+		buildCmd := exec.Command("make", "build")
+		buildCmd.Dir = projectRoot
+		if buildErr := buildCmd.Run(); buildErr != nil {
+			t.Fatalf("Failed to build plugin: %v", buildErr)
+		}
+	}
+
+	tempDir := t.TempDir()
+	genDir := filepath.Join(tempDir, "gen")
+	if mkErr := os.MkdirAll(genDir, 0o755); mkErr != nil {
+		t.Fatal(mkErr)
+	}
+
+	cmd := exec.Command("protoc",
+		"--plugin=protoc-gen-go-http="+pluginPath,
+		"--go_out="+genDir,
+		"--go_opt=paths=source_relative",
+		"--go-http_out="+genDir,
+		"--go-http_opt=paths=source_relative",
+		"--proto_path="+protoDir,
+		"--proto_path="+filepath.Join(projectRoot, "proto"),
+		"report_service.proto",
+	)
+	cmd.Dir = protoDir
+	out, runErr := cmd.CombinedOutput()
+	if runErr != nil {
+		t.Fatalf("protoc failed: %v\n%s", runErr, string(out))
+	}
+
+	goMod := `module report_service_test
+
+go 1.24
+
+require (
+	google.golang.org/protobuf ` + extractProtobufVersionFromModFile(t, projectRoot) + `
+	github.com/SebastienMelki/sebuf v0.0.0
+)
+
+replace github.com/SebastienMelki/sebuf => ` + projectRoot + `
+`
+	if writeErr := os.WriteFile(filepath.Join(tempDir, "go.mod"), []byte(goMod), 0o644); writeErr != nil {
+		t.Fatal(writeErr)
+	}
+	if writeErr := os.WriteFile(
+		filepath.Join(tempDir, "report_service_test.go"),
+		[]byte(reportServiceIntegrationTestCode()),
+		0o644,
+	); writeErr != nil {
+		t.Fatal(writeErr)
+	}
+
+	tidyCmd := exec.Command("go", "mod", "tidy")
+	tidyCmd.Dir = tempDir
+	if tidyOut, tidyErr := tidyCmd.CombinedOutput(); tidyErr != nil {
+		t.Fatalf("go mod tidy failed: %v\n%s", tidyErr, string(tidyOut))
+	}
+
+	testCmd := exec.Command("go", "test", "-v", "-count=1", "./...")
+	testCmd.Dir = tempDir
+	testOut, testErr := testCmd.CombinedOutput()
+
+	t.Logf("Test output:\n%s", string(testOut))
+
+	if testErr != nil {
+		t.Fatalf("integration tests failed: %v", testErr)
+	}
+}
+
+// reportServiceIntegrationTestCode is the test source that runs inside the temp module.
+// It serves the generated ReportService through httptest with an ErrorHandler that marks
+// every error response, and checks status codes, headers and bodies.
+func reportServiceIntegrationTestCode() string {
+	return `package report_service_test
+
+import (
+	"context"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	gen "report_service_test/gen"
+
+	"google.golang.org/protobuf/proto"
+
+	sebufhttp "github.com/SebastienMelki/sebuf/http"
+)
+
+type reportServer struct {
+	generating chan struct{}
+	release    chan struct{}
+}
+
+func (s *reportServer) GetReport(_ context.Context, req *gen.GetReportRequest) (*gen.Report, error) {
+	return &gen.Report{Id: req.Id, Format: req.Format}, nil
+}
+
+func (s *reportServer) PublishReport(_ context.Context, req *gen.PublishReportRequest) (*gen.Report, error) {
+	return &gen.Report{Id: req.Id, Title: proto.String("published")}, nil
+}
+
+func (s *reportServer) GenerateReport(_ context.Context, req *gen.GenerateReportRequest) (*gen.Report, error) {
+	s.generating <- struct{}{}
+	<-s.release
+	return &gen.Report{Title: req.Title}, nil
+}
+
+// markErrors is the ErrorHandler of the test server: it tags the response so the tests
+// can tell it ran, and keeps the default body.
+func markErrors(w http.ResponseWriter, _ *http.Request, _ error) proto.Message {
+	w.Header().Set("X-Error-Handler", "called")
+	return nil
+}
+
+func newTestServer(t *testing.T) (*reportServer, *httptest.Server) {
+	t.Helper()
+	server := &reportServer{generating: make(chan struct{}), release: make(chan struct{})}
+	mux := http.NewServeMux()
+	if err := gen.RegisterReportServiceServer(server, gen.WithMux(mux), gen.WithErrorHandler(markErrors)); err != nil {
+		t.Fatalf("RegisterReportServiceServer: %v", err)
+	}
+	srv := httptest.NewServer(mux)
+	t.Cleanup(srv.Close)
+	return server, srv
+}
+
+func do(t *testing.T, method, url, body string, header map[string]string) (*http.Response, string) {
+	t.Helper()
+	req, err := http.NewRequest(method, url, strings.NewReader(body))
+	if err != nil {
+		t.Fatalf("NewRequest: %v", err)
+	}
+	if body != "" {
+		req.Header.Set("Content-Type", "application/json")
+	}
+	for name, value := range header {
+		req.Header.Set(name, value)
+	}
+	resp, err := http.DefaultClient.Do(req)
+	if err != nil {
+		t.Fatalf("Do: %v", err)
+	}
+	defer resp.Body.Close()
+	respBody, err := io.ReadAll(resp.Body)
+	if err != nil {
+		t.Fatalf("ReadAll: %v", err)
+	}
+	return resp, string(respBody)
+}
+
+func TestRequiredField(t *testing.T) {
+	_, srv := newTestServer(t)
+
+	resp, body := do(t, http.MethodGet, srv.URL+"/api/reports/r1", "", nil)
+	if resp.StatusCode != http.StatusBadRequest {
+		t.Fatalf("missing required format: status %d, want 400: %s", resp.StatusCode, body)
+	}
+	if !strings.Contains(body, "format") {
+		t.Errorf("400 body does not name the missing field: %s", body)
+	}
+
+	resp, body = do(t, http.MethodGet, srv.URL+"/api/reports/r1?format=pdf", "", nil)
+	if resp.StatusCode != http.StatusOK || !strings.Contains(body, "pdf") {
+		t.Errorf("with format: status %d, body %s, want 200 echoing the format", resp.StatusCode, body)
+	}
+}
+
+func TestCustomVerb(t *testing.T) {
+	_, srv := newTestServer(t)
+
+	resp, body := do(t, http.MethodPost, srv.URL+"/api/reports/r1:publish", "{}", nil)
+	if resp.StatusCode != http.StatusOK {
+		t.Fatalf("status %d, want 200: %s", resp.StatusCode, body)
+	}
+	if !strings.Contains(body, "\"id\":\"r1\"") || !strings.Contains(body, "published") {
+		t.Errorf("PublishReport was not called with id r1: %s", body)
+	}
+
+	resp, body = do(t, http.MethodPost, srv.URL+"/api/reports/r1:archive", "{}", nil)
+	if resp.StatusCode != http.StatusNotFound {
+		t.Errorf("unknown verb: status %d, want 404: %s", resp.StatusCode, body)
+	}
+}
+
+func TestConcurrencyLimit(t *testing.T) {
+	server, srv := newTestServer(t)
+	defer close(server.release)
+
+	go func() {
+		resp, err := http.Post(srv.URL+"/api/reports", "application/json", strings.NewReader("{\"title\":\"q1\"}"))
+		if err == nil {
+			resp.Body.Close()
+		}
+	}()
+	<-server.generating
+
+	resp, body := do(t, http.MethodPost, srv.URL+"/api/reports", "{\"title\":\"q2\"}", nil)
+	if resp.StatusCode != http.StatusServiceUnavailable {
+		t.Fatalf("status %d, want 503: %s", resp.StatusCode, body)
+	}
+	if got := resp.Header.Get("Retry-After"); got != "1" {
+		t.Errorf("Retry-After = %q, want 1", got)
+	}
+	if resp.Header.Get("X-Error-Handler") != "called" {
+		t.Error("the 503 did not go through the ErrorHandler")
+	}
+	if !strings.Contains(body, "too many concurrent requests") {
+		t.Errorf("unexpected 503 body: %s", body)
+	}
+}
+
+func TestAPIVersion(t *testing.T) {
+	_, srv := newTestServer(t)
+
+	resp, body := do(t, http.MethodGet, srv.URL+"/api/reports/r1?format=pdf", "",
+		map[string]string{"X-API-Version": "2025-06-01"})
+	if resp.StatusCode != http.StatusOK {
+		t.Fatalf("supported version: status %d, want 200: %s", resp.StatusCode, body)
+	}
+
+	resp, body = do(t, http.MethodGet, srv.URL+"/api/reports/r1?format=pdf", "",
+		map[string]string{"X-API-Version": "1999-01-01", "Accept": "application/x-protobuf"})
+	if resp.StatusCode != http.StatusNotAcceptable {
+		t.Fatalf("unsupported version: status %d, want 406: %s", resp.StatusCode, body)
+	}
+	if got := resp.Header.Get("X-API-Version"); got != "2024-01-01, 2025-06-01" {
+		t.Errorf("X-API-Version = %q, want the supported versions", got)
+	}
+	if resp.Header.Get("X-Error-Handler") != "called" {
+		t.Error("the 406 did not go through the ErrorHandler")
+	}
+	if got := resp.Header.Get("Content-Type"); got != "application/x-protobuf" {
+		t.Errorf("Content-Type = %q, want the negotiated application/x-protobuf", got)
+	}
+	var apiErr sebufhttp.Error
+	if err := proto.Unmarshal([]byte(body), &apiErr); err != nil ||
+		!strings.Contains(apiErr.GetMessage(), "unsupported API version") {
+		t.Errorf("406 body = %v (%v), want a protobuf Error", &apiErr, err)
+	}
+}
+`
+}
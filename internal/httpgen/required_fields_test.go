@@ -0,0 +1,107 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+// proto2ReportFile builds a proto2 file whose request message has a required field,
+// optionally hidden one level down in a nested message.
+func proto2ReportFile(nested bool) *descriptorpb.FileDescriptorProto {
+	required := &descriptorpb.FieldDescriptorProto{
+		Name:     proto.String("id"),
+		Number:   proto.Int32(1),
+		Label:    descriptorpb.FieldDescriptorProto_LABEL_REQUIRED.Enum(),
+		Type:     descriptorpb.FieldDescriptorProto_TYPE_STRING.Enum(),
+		JsonName: proto.String("id"),
+	}
+	messages := []*descriptorpb.DescriptorProto{
+		{Name: proto.String("GetReportRequest"), Field: []*descriptorpb.FieldDescriptorProto{required}},
+		{Name: proto.String("Report")},
+	}
+	if nested {
+		messages[0].Field = []*descriptorpb.FieldDescriptorProto{{
+			Name:     proto.String("filter"),
+			Number:   proto.Int32(1),
+			Label:    descriptorpb.FieldDescriptorProto_LABEL_OPTIONAL.Enum(),
+			Type:     descriptorpb.FieldDescriptorProto_TYPE_MESSAGE.Enum(),
+			TypeName: proto.String(".test.reports.v1.Filter"),
+			JsonName: proto.String("filter"),
+		}}
+		messages = append(messages, &descriptorpb.DescriptorProto{
+			Name:  proto.String("Filter"),
+			Field: []*descriptorpb.FieldDescriptorProto{required},
+		})
+	}
+
+	methodOptions := &descriptorpb.MethodOptions{}
+	proto.SetExtension(methodOptions, http.E_Config, &http.HttpConfig{
+		Path:   "/reports",
+		Method: http.HttpMethod_HTTP_METHOD_POST,
+	})
+	return &descriptorpb.FileDescriptorProto{
+		Name:    proto.String("reports.proto"),
+		Package: proto.String("test.reports.v1"),
+		Syntax:  proto.String("proto2"),
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("github.com/SebastienMelki/sebuf/internal/httpgen/testreports"),
+		},
+		MessageType: messages,
+		Service: []*descriptorpb.ServiceDescriptorProto{{
+			Name: proto.String("ReportService"),
+			Method: []*descriptorpb.MethodDescriptorProto{{
+				Name:       proto.String("GetReport"),
+				InputType:  proto.String(".test.reports.v1.GetReportRequest"),
+				OutputType: proto.String(".test.reports.v1.Report"),
+				Options:    methodOptions,
+			}},
+		}},
+	}
+}
+
+func TestRequiredFieldsCheck(t *testing.T) {
+	const check = "requiredFieldViolations(msg.ProtoReflect(), \"\")"
+
+	tests := []struct {
+		name      string
+		binding   func(t *testing.T) string
+		wantCheck bool
+	}{
+		{
+			name: "proto2 required field",
+			binding: func(t *testing.T) string {
+				return generateFromDescriptor(t, Options{}, proto2ReportFile(false), "_http_binding.pb.go")
+			},
+			wantCheck: true,
+		},
+		{
+			name: "required field in nested message",
+			binding: func(t *testing.T) string {
+				return generateFromDescriptor(t, Options{}, proto2ReportFile(true), "_http_binding.pb.go")
+			},
+			wantCheck: true,
+		},
+		{
+			name:      "proto3 file",
+			binding:   func(t *testing.T) string { return generateBindingForOptions(t, Options{}) },
+			wantCheck: false,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			binding := tt.binding(t)
+			if got := strings.Contains(binding, check); got != tt.wantCheck {
+				t.Errorf("required-field check emitted = %v, want %v", got, tt.wantCheck)
+			}
+			if got := strings.Contains(binding, "func requiredFieldViolations("); got != tt.wantCheck {
+				t.Errorf("requiredFieldViolations helper emitted = %v, want %v", got, tt.wantCheck)
+			}
+		})
+	}
+}
@@ -597,6 +597,17 @@ func defaultErrorStatusCode(err error) int {
 
 // writeErrorWithHandler calls custom handler if set, then marshals response
 func writeErrorWithHandler(w http.ResponseWriter, r *http.Request, err error, handler ErrorHandler, marshalOpts protojson.MarshalOptions) {
+	writeErrorWithStatus(w, r, err, defaultErrorStatusCode(err), handler, marshalOpts)
+}
+
+// writeError writes err with statusCode through the configured error handler and marshal
+// options. It is the sebufhttp.ErrorWriter passed to the sebufhttp handler wrappers.
+func (c *serverConfiguration) writeError(w http.ResponseWriter, r *http.Request, statusCode int, err error) {
+	writeErrorWithStatus(w, r, err, statusCode, c.errorHandler, c.marshalOpts)
+}
+
+// writeErrorWithStatus calls custom handler if set, then marshals response with statusCode
+func writeErrorWithStatus(w http.ResponseWriter, r *http.Request, err error, statusCode int, handler ErrorHandler, marshalOpts protojson.MarshalOptions) {
 	var response proto.Message
 	var capture *responseCapture
 
@@ -613,9 +624,6 @@ func writeErrorWithHandler(w http.ResponseWriter, r *http.Request, err error, ha
 		response = defaultErrorResponse(err)
 	}
 
-	// Determine status code
-	statusCode := defaultErrorStatusCode(err)
-
 	// If handler already set status, don't set it again
 	if capture != nil && capture.wroteHeader {
 		// Handler set status, just write the body
@@ -0,0 +1,44 @@
+package httpgen
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/http"
+)
+
+// generateBindingForOptions returns the binding file generated for a POST /notes method.
+func generateBindingForOptions(t *testing.T, opts Options) string {
+	t.Helper()
+	return generateNoteService(t, opts, &http.HttpConfig{
+		Path:   "/notes",
+		Method: http.HttpMethod_HTTP_METHOD_POST,
+	}, "_http_binding.pb.go")
+}
+
+func TestUnknownFieldsPolicy(t *testing.T) {
+	t.Run("reject by default", func(t *testing.T) {
+		binding := generateBindingForOptions(t, Options{})
+
+		if !strings.Contains(binding, "err = protojson.Unmarshal(bodyBytes, protoRequest)") {
+			t.Error("expected strict protojson.Unmarshal in JSON binding")
+		}
+		if strings.Contains(binding, "DiscardUnknown") {
+			t.Error("default binding must not discard unknown fields")
+		}
+	})
+
+	t.Run("ignore discards unknown fields", func(t *testing.T) {
+		binding := generateBindingForOptions(t, Options{DiscardUnknownFields: true})
+
+		for _, want := range []string{
+			"opts := protojson.UnmarshalOptions{DiscardUnknown: true}",
+			"return u.UnmarshalJSONSebuf(bodyBytes, opts)",
+			"err = opts.Unmarshal(bodyBytes, protoRequest)",
+		} {
+			if !strings.Contains(binding, want) {
+				t.Errorf("binding file missing %q", want)
+			}
+		}
+	})
+}
@@ -2,6 +2,7 @@ package httpgen
 
 import (
 	"fmt"
+	"strings"
 
 	"google.golang.org/protobuf/compiler/protogen"
 	"google.golang.org/protobuf/reflect/protoreflect"
@@ -62,6 +63,27 @@ func ValidateMethodConfig(service *protogen.Service, method *protogen.Method) []
 		}
 	}
 
+	// 2b. Path variables must span a whole segment: net/http.ServeMux wildcards cannot share
+	// a segment with literal text. The one exception is a custom verb ending the path
+	// (AIP-136 "{id}:activate"), which the generated Register function dispatches itself.
+	if segment := findPartialWildcardSegment(config.Path); segment != "" {
+		errors = append(errors, ValidationError{
+			Service: serviceName,
+			Method:  methodName,
+			Message: fmt.Sprintf(
+				"path segment '%s' in path '%s' mixes a path variable with literal text. "+
+					"Go's http.ServeMux requires a path variable to be a whole segment; the only exception "+
+					"is a custom verb at the end of the path (e.g. '/users/{id}:activate'). "+
+					"Move the text into its own segment (e.g. '/users/{id}/activate').",
+				segment, config.Path),
+		})
+	}
+
+	// 2c. Documented responses must use valid, distinct status codes and known messages
+	if _, err := annotations.GetMethodResponses(method); err != nil {
+		errors = append(errors, ValidationError{Service: serviceName, Method: methodName, Message: err.Error()})
+	}
+
 	// 3. Validate query parameter fields don't conflict with path params
 	queryParams := annotations.GetQueryParams(method.Input)
 	for _, qp := range queryParams {
@@ -109,6 +131,23 @@ func ValidateMethodConfig(service *protogen.Service, method *protogen.Method) []
 	return errors
 }
 
+// findPartialWildcardSegment returns the first path segment that contains a path
+// variable alongside other text (e.g. "report-{id}"), or "" if there is none. A custom
+// verb ending the path ("{id}:activate") is allowed.
+func findPartialWildcardSegment(path string) string {
+	path, _ = annotations.SplitCustomVerb(path)
+	for _, segment := range strings.Split(path, "/") {
+		if !strings.Contains(segment, "{") {
+			continue
+		}
+		if !strings.HasPrefix(segment, "{") || !strings.HasSuffix(segment, "}") ||
+			strings.Count(segment, "{") != 1 {
+			return segment
+		}
+	}
+	return ""
+}
+
 // findFieldByProtoName finds a field in a message by its proto name.
 func findFieldByProtoName(message *protogen.Message, fieldName string) *protogen.Field {
 	for _, field := range message.Fields {
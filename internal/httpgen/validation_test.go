@@ -280,6 +280,30 @@ func isMethodWithoutBody(method string) bool {
 	return method == "GET" || method == "DELETE"
 }
 
+func TestFindPartialWildcardSegment(t *testing.T) {
+	tests := []struct {
+		name string
+		path string
+		want string
+	}{
+		{"plain path variable", "/v1/users/{id}", ""},
+		{"literal custom verb", "/v1/users:batchGet", ""},
+		{"verb in own segment", "/v1/users/{id}/activate", ""},
+		{"custom verb after variable", "/v1/users/{id}:activate", ""},
+		{"custom verb before last segment", "/v1/users/{id}:activate/posts", "{id}:activate"},
+		{"prefix before variable", "/v1/files/report-{id}", "report-{id}"},
+		{"two variables in one segment", "/v1/{a}{b}", "{a}{b}"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := findPartialWildcardSegment(tt.path); got != tt.want {
+				t.Errorf("findPartialWildcardSegment(%q) = %q, want %q", tt.path, got, tt.want)
+			}
+		})
+	}
+}
+
 // Benchmark tests.
 func BenchmarkGetUnboundFields(b *testing.B) {
 	allFields := []string{"id", "name", "email", "phone", "address", "city", "country", "zip"}
@@ -0,0 +1,176 @@
+package openapiv3
+
+import (
+	"fmt"
+
+	yaml "go.yaml.in/yaml/v4"
+	k8syaml "sigs.k8s.io/yaml"
+)
+
+// asyncAPIVersion is the AsyncAPI specification version RenderAsyncAPI targets.
+const asyncAPIVersion = "3.0.0"
+
+// eventChannel is a stream: true RPC recorded for the AsyncAPI document: the events of
+// message are sent on address.
+type eventChannel struct {
+	name        string
+	address     string
+	message     string
+	description string
+}
+
+type asyncAPIDocument struct {
+	AsyncAPI           string                       `yaml:"asyncapi"`
+	Info               asyncAPIInfo                 `yaml:"info"`
+	DefaultContentType string                       `yaml:"defaultContentType"`
+	Channels           map[string]asyncAPIChannel   `yaml:"channels"`
+	Operations         map[string]asyncAPIOperation `yaml:"operations"`
+	Components         asyncAPIComponents           `yaml:"components"`
+}
+
+type asyncAPIInfo struct {
+	Title       string `yaml:"title"`
+	Version     string `yaml:"version"`
+	Description string `yaml:"description,omitempty"`
+}
+
+type asyncAPIChannel struct {
+	Address     string                       `yaml:"address"`
+	Description string                       `yaml:"description,omitempty"`
+	Messages    map[string]asyncAPIRef       `yaml:"messages"`
+	Parameters  map[string]asyncAPIParameter `yaml:"parameters,omitempty"`
+}
+
+type asyncAPIParameter struct {
+	Description string `yaml:"description"`
+}
+
+type asyncAPIOperation struct {
+	Action   string        `yaml:"action"`
+	Channel  asyncAPIRef   `yaml:"channel"`
+	Messages []asyncAPIRef `yaml:"messages"`
+}
+
+type asyncAPIComponents struct {
+	Messages map[string]asyncAPIMessage `yaml:"messages"`
+	Schemas  *yaml.Node                 `yaml:"schemas,omitempty"`
+}
+
+type asyncAPIMessage struct {
+	Name    string      `yaml:"name"`
+	Payload asyncAPIRef `yaml:"payload"`
+}
+
+type asyncAPIRef struct {
+	Ref string `yaml:"$ref"`
+}
+
+// HasEventChannels reports whether any processed RPC streams events, i.e. whether
+// RenderAsyncAPI has anything to describe.
+func (g *Generator) HasEventChannels() bool {
+	return len(g.channels) > 0
+}
+
+// recordEventChannel remembers a stream: true RPC for RenderAsyncAPI.
+func (g *Generator) recordEventChannel(name, address, message, description string) {
+	g.channels = append(g.channels, eventChannel{
+		name:        name,
+		address:     address,
+		message:     message,
+		description: description,
+	})
+}
+
+// RenderAsyncAPI renders an AsyncAPI 3 document describing the event streams of the
+// processed RPCs annotated with stream: true, which OpenAPI can only describe as an
+// opaque text stream. Each RPC becomes a channel at its HTTP path, carrying its output
+// message, and a send operation on that channel. The component schemas are shared with the OpenAPI
+// document, so message payloads reference "#/components/schemas/{Name}" as there.
+func (g *Generator) RenderAsyncAPI(format OutputFormat) ([]byte, error) {
+	schemas, err := g.schemasNode()
+	if err != nil {
+		return nil, err
+	}
+
+	doc := asyncAPIDocument{
+		AsyncAPI: asyncAPIVersion,
+		Info: asyncAPIInfo{
+			Title:       g.doc.Info.Title,
+			Version:     g.doc.Info.Version,
+			Description: g.doc.Info.Description,
+		},
+		DefaultContentType: "application/json",
+		Channels:           make(map[string]asyncAPIChannel, len(g.channels)),
+		Operations:         make(map[string]asyncAPIOperation, len(g.channels)),
+		Components: asyncAPIComponents{
+			Messages: make(map[string]asyncAPIMessage),
+			Schemas:  schemas,
+		},
+	}
+	for _, channel := range g.channels {
+		var parameters map[string]asyncAPIParameter
+		if names, templateErr := pathTemplateParams(channel.address); templateErr == nil && len(names) > 0 {
+			parameters = make(map[string]asyncAPIParameter, len(names))
+			for _, name := range names {
+				parameters[name] = asyncAPIParameter{Description: "Path parameter " + name}
+			}
+		}
+		doc.Channels[channel.name] = asyncAPIChannel{
+			Address:     channel.address,
+			Description: channel.description,
+			Messages: map[string]asyncAPIRef{
+				channel.message: {Ref: "#/components/messages/" + channel.message},
+			},
+			Parameters: parameters,
+		}
+		doc.Operations[channel.name] = asyncAPIOperation{
+			Action:   "send",
+			Channel:  asyncAPIRef{Ref: "#/channels/" + channel.name},
+			Messages: []asyncAPIRef{{Ref: "#/channels/" + channel.name + "/messages/" + channel.message}},
+		}
+		doc.Components.Messages[channel.message] = asyncAPIMessage{
+			Name:    channel.message,
+			Payload: asyncAPIRef{Ref: schemaRefPrefix + channel.message},
+		}
+	}
+
+	data, err := yaml.Marshal(doc)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal AsyncAPI document: %w", err)
+	}
+	if format != FormatJSON {
+		return data, nil
+	}
+	jsonData, err := k8syaml.YAMLToJSON(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert YAML to JSON: %w", err)
+	}
+	return jsonData, nil
+}
+
+// schemasNode returns the rendered components.schemas of the OpenAPI document, or an
+// empty mapping when it has none.
+func (g *Generator) schemasNode() (*yaml.Node, error) {
+	empty := &yaml.Node{Kind: yaml.MappingNode, Tag: "!!map"}
+	data, err := yaml.Marshal(g.doc)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal to YAML: %w", err)
+	}
+	var root yaml.Node
+	if unmarshalErr := yaml.Unmarshal(data, &root); unmarshalErr != nil {
+		return nil, fmt.Errorf("failed to parse rendered document: %w", unmarshalErr)
+	}
+	if len(root.Content) == 0 {
+		return empty, nil
+	}
+	doc := root.Content[0]
+	index := mappingIndex(doc, "components")
+	if index < 0 {
+		return empty, nil
+	}
+	components := doc.Content[index+1]
+	if index = mappingIndex(components, "schemas"); index < 0 {
+		return empty, nil
+	}
+	return components.Content[index+1], nil
+}
@@ -0,0 +1,93 @@
+package openapiv3_test
+
+import (
+	"testing"
+
+	yaml "go.yaml.in/yaml/v4"
+	"google.golang.org/protobuf/proto"
+
+	"github.com/SebastienMelki/sebuf/http"
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+func TestRenderAsyncAPI(t *testing.T) {
+	if gen := generateService(t, buildDescPlugin(t, userServiceFile(nil)), "UserService"); gen.HasEventChannels() {
+		t.Error("a service without streaming RPCs should have no event channels")
+	}
+
+	// The generated handlers serve a server-streaming RPC without stream: true as a single
+	// JSON response, so it is no event channel.
+	file := userServiceFile(nil)
+	list := withHTTPConfig(descMethod("ListUserChanges", "GetUserRequest", "User"), &http.HttpConfig{
+		Path:   "/users/{id}/changes",
+		Method: http.HttpMethod_HTTP_METHOD_GET,
+	})
+	list.ServerStreaming = proto.Bool(true)
+	file.Service[0].Method = append(file.Service[0].Method, list)
+	if generateService(t, buildDescPlugin(t, file), "UserService").HasEventChannels() {
+		t.Error("a server-streaming RPC without stream: true should have no event channel")
+	}
+
+	watch := withHTTPConfig(descMethod("WatchUser", "GetUserRequest", "User"), &http.HttpConfig{
+		Path:   "/users/{id}/events",
+		Method: http.HttpMethod_HTTP_METHOD_GET,
+		Stream: true,
+	})
+	file.Service[0].Method = append(file.Service[0].Method, watch)
+	gen := generateService(t, buildDescPlugin(t, file), "UserService")
+	if !gen.HasEventChannels() {
+		t.Fatal("a stream: true RPC should be an event channel")
+	}
+
+	for _, format := range []openapiv3.OutputFormat{openapiv3.FormatYAML, openapiv3.FormatJSON} {
+		data, err := gen.RenderAsyncAPI(format)
+		if err != nil {
+			t.Fatalf("RenderAsyncAPI(%v) error = %v", format, err)
+		}
+		var doc struct {
+			AsyncAPI string `yaml:"asyncapi"`
+			Channels map[string]struct {
+				Address    string         `yaml:"address"`
+				Parameters map[string]any `yaml:"parameters"`
+			} `yaml:"channels"`
+			Operations map[string]struct {
+				Action  string `yaml:"action"`
+				Channel struct {
+					Ref string `yaml:"$ref"`
+				} `yaml:"channel"`
+			} `yaml:"operations"`
+			Components struct {
+				Messages map[string]struct {
+					Payload struct {
+						Ref string `yaml:"$ref"`
+					} `yaml:"payload"`
+				} `yaml:"messages"`
+				Schemas map[string]any `yaml:"schemas"`
+			} `yaml:"components"`
+		}
+		if unmarshalErr := yaml.Unmarshal(data, &doc); unmarshalErr != nil {
+			t.Fatalf("parsing %v output: %v", format, unmarshalErr)
+		}
+
+		if doc.AsyncAPI != "3.0.0" {
+			t.Errorf("asyncapi = %q, want 3.0.0", doc.AsyncAPI)
+		}
+		if len(doc.Channels) != 1 {
+			t.Fatalf("channels = %v, want only WatchUser", doc.Channels)
+		}
+		channel := doc.Channels["WatchUser"]
+		if channel.Address != "/users/{id}/events" || channel.Parameters["id"] == nil {
+			t.Errorf("WatchUser channel = %+v, want address /users/{id}/events with an id parameter", channel)
+		}
+		operation := doc.Operations["WatchUser"]
+		if operation.Action != "send" || operation.Channel.Ref != "#/channels/WatchUser" {
+			t.Errorf("WatchUser operation = %+v, want send on #/channels/WatchUser", operation)
+		}
+		if ref := doc.Components.Messages["User"].Payload.Ref; ref != "#/components/schemas/User" {
+			t.Errorf("User message payload = %q, want #/components/schemas/User", ref)
+		}
+		if _, ok := doc.Components.Schemas["User"]; !ok {
+			t.Error("components.schemas has no User schema")
+		}
+	}
+}
@@ -59,6 +59,37 @@ func TestBundleGoldenFiles(t *testing.T) {
 			goldenFile: "testdata/golden/json/bundle_multiple_services.openapi.json",
 			format:     "json",
 		},
+		// A service_config host is attached to the service's operations, since the
+		// document-level servers span every service.
+		{
+			name:       "bundle_service_host_yaml",
+			protoFile:  "testdata/proto/service_host.proto",
+			outputName: "origin.openapi.yaml",
+			goldenFile: "testdata/golden/yaml/bundle_service_host.openapi.yaml",
+			format:     "yaml",
+		},
+		{
+			name:       "bundle_service_host_json",
+			protoFile:  "testdata/proto/service_host.proto",
+			outputName: "origin.openapi.json",
+			goldenFile: "testdata/golden/json/bundle_service_host.openapi.json",
+			format:     "json",
+		},
+		// Messages from imported files that protoc does not generate still get schemas.
+		{
+			name:       "bundle_imported_schemas_yaml",
+			protoFile:  "testdata/proto/imported_schemas.proto",
+			outputName: "origin.openapi.yaml",
+			goldenFile: "testdata/golden/yaml/bundle_imported_schemas.openapi.yaml",
+			format:     "yaml",
+		},
+		{
+			name:       "bundle_imported_schemas_json",
+			protoFile:  "testdata/proto/imported_schemas.proto",
+			outputName: "origin.openapi.json",
+			goldenFile: "testdata/golden/json/bundle_imported_schemas.openapi.json",
+			format:     "json",
+		},
 	}
 
 	for _, tc := range testCases {
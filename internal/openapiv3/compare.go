@@ -0,0 +1,203 @@
+package openapiv3
+
+import (
+	"fmt"
+	"slices"
+
+	yaml "go.yaml.in/yaml/v4"
+)
+
+// Categories of the breaking changes reported by CompareDocuments.
+const (
+	ChangeRemovedOperation     = "removed operation"
+	ChangeRemovedSchema        = "removed schema"
+	ChangeRemovedProperty      = "removed property"
+	ChangeNarrowedType         = "narrowed type"
+	ChangeNewRequiredField     = "new required field"
+	ChangeNewRequiredParameter = "new required parameter"
+)
+
+// CompareDocuments reports the changes from previous to current, two rendered OpenAPI
+// documents (YAML or JSON), that can break existing clients:
+//   - an operation removed from paths or webhooks
+//   - a component schema, or a property of one, removed
+//   - a type narrowed: an allowed type, an enum value or a $ref target dropped, or a
+//     format changed
+//   - a property newly required, or a new required parameter
+//
+// Each change reads "category: location[: detail]", sorted so changes of the same
+// category are grouped. It returns nil when nothing breaks.
+func CompareDocuments(previous, current []byte) ([]string, error) {
+	var oldDoc, newDoc map[string]any
+	if err := yaml.Unmarshal(previous, &oldDoc); err != nil {
+		return nil, fmt.Errorf("failed to parse previous OpenAPI document: %w", err)
+	}
+	if err := yaml.Unmarshal(current, &newDoc); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI document: %w", err)
+	}
+
+	var changes []string
+	for _, section := range []string{"paths", "webhooks"} {
+		oldItems, _ := oldDoc[section].(map[string]any)
+		newItems, _ := newDoc[section].(map[string]any)
+		changes = append(changes, compareOperations(section, oldItems, newItems)...)
+	}
+	changes = append(changes, compareSchemas(componentSchemas(oldDoc), componentSchemas(newDoc))...)
+	slices.Sort(changes)
+	return changes, nil
+}
+
+// compareOperations reports removed operations and new required parameters between the
+// path items of one section (paths or webhooks).
+func compareOperations(section string, oldItems, newItems map[string]any) []string {
+	var changes []string
+	for _, key := range sortedKeys(oldItems) {
+		oldItem, _ := oldItems[key].(map[string]any)
+		newItem, _ := newItems[key].(map[string]any)
+		for _, method := range operationMethods {
+			oldOperation, ok := oldItem[method].(map[string]any)
+			if !ok {
+				continue
+			}
+			location := fmt.Sprintf("%s %s %s", section, key, method)
+			newOperation, found := newItem[method].(map[string]any)
+			if !found {
+				changes = append(changes, ChangeRemovedOperation+": "+location)
+				continue
+			}
+
+			before := requiredParameters(oldItem["parameters"], oldOperation["parameters"])
+			for _, parameter := range requiredParameters(newItem["parameters"], newOperation["parameters"]) {
+				if !slices.Contains(before, parameter) {
+					changes = append(changes,
+						fmt.Sprintf("%s: %s: %s", ChangeNewRequiredParameter, location, parameter))
+				}
+			}
+		}
+	}
+	return changes
+}
+
+// requiredParameters lists the required parameters of parameter lists as "in name".
+func requiredParameters(lists ...any) []string {
+	var required []string
+	for _, list := range lists {
+		parameters, _ := list.([]any)
+		for _, parameter := range parameters {
+			fields, _ := parameter.(map[string]any)
+			if isRequired, _ := fields["required"].(bool); !isRequired {
+				continue
+			}
+			in, _ := fields["in"].(string)
+			name, _ := fields["name"].(string)
+			required = append(required, in+" "+name)
+		}
+	}
+	return required
+}
+
+// componentSchemas returns components.schemas of a parsed document.
+func componentSchemas(doc map[string]any) map[string]any {
+	components, _ := doc["components"].(map[string]any)
+	schemas, _ := components["schemas"].(map[string]any)
+	return schemas
+}
+
+// compareSchemas reports removed schemas and the breaking changes of the schemas that
+// are in both documents.
+func compareSchemas(oldSchemas, newSchemas map[string]any) []string {
+	var changes []string
+	for _, name := range sortedKeys(oldSchemas) {
+		oldSchema, _ := oldSchemas[name].(map[string]any)
+		newSchema, found := newSchemas[name].(map[string]any)
+		if !found {
+			changes = append(changes, ChangeRemovedSchema+": "+name)
+			continue
+		}
+		changes = append(changes, compareSchema(name, oldSchema, newSchema)...)
+	}
+	return changes
+}
+
+// compareSchema reports removed properties, narrowed types and newly required properties
+// of one component schema.
+func compareSchema(name string, oldSchema, newSchema map[string]any) []string {
+	changes := compareTypes(name, oldSchema, newSchema)
+
+	oldProperties, _ := oldSchema["properties"].(map[string]any)
+	newProperties, _ := newSchema["properties"].(map[string]any)
+	for _, property := range sortedKeys(oldProperties) {
+		location := name + "." + property
+		newProperty, found := newProperties[property].(map[string]any)
+		if !found {
+			changes = append(changes, ChangeRemovedProperty+": "+location)
+			continue
+		}
+		oldProperty, _ := oldProperties[property].(map[string]any)
+		changes = append(changes, compareTypes(location, oldProperty, newProperty)...)
+	}
+
+	oldRequired := stringList(oldSchema["required"])
+	for _, property := range stringList(newSchema["required"]) {
+		if !slices.Contains(oldRequired, property) {
+			changes = append(changes, ChangeNewRequiredField+": "+name+"."+property)
+		}
+	}
+	return changes
+}
+
+// compareTypes reports how the type of a schema narrowed, descending into array items.
+func compareTypes(location string, oldSchema, newSchema map[string]any) []string {
+	var changes []string
+	narrowed := func(format string, args ...any) {
+		changes = append(changes, ChangeNarrowedType+": "+location+": "+fmt.Sprintf(format, args...))
+	}
+
+	oldRef, _ := oldSchema["$ref"].(string)
+	if newRef, _ := newSchema["$ref"].(string); oldRef != "" && newRef != oldRef {
+		narrowed("$ref %q is now %q", oldRef, newRef)
+	}
+	newTypes := stringList(newSchema["type"])
+	for _, typ := range stringList(oldSchema["type"]) {
+		if len(newTypes) > 0 && !slices.Contains(newTypes, typ) {
+			narrowed("type %q no longer allowed", typ)
+		}
+	}
+	oldFormat, _ := oldSchema["format"].(string)
+	if newFormat, _ := newSchema["format"].(string); oldFormat != "" && newFormat != oldFormat {
+		narrowed("format %q is now %q", oldFormat, newFormat)
+	}
+	if newEnum, constrained := newSchema["enum"].([]any); constrained {
+		oldEnum, wasConstrained := oldSchema["enum"].([]any)
+		if !wasConstrained {
+			narrowed("enum constraint added")
+		}
+		for _, value := range oldEnum {
+			if !slices.Contains(newEnum, value) {
+				narrowed("enum value %v removed", value)
+			}
+		}
+	}
+
+	oldItems, _ := oldSchema["items"].(map[string]any)
+	if newItems, ok := newSchema["items"].(map[string]any); ok && oldItems != nil {
+		changes = append(changes, compareTypes(location+"[]", oldItems, newItems)...)
+	}
+	return changes
+}
+
+// stringList returns a string or a list of strings (such as `type` or `required`) as a
+// slice.
+func stringList(value any) []string {
+	if single, ok := value.(string); ok {
+		return []string{single}
+	}
+	list, _ := value.([]any)
+	values := make([]string, 0, len(list))
+	for _, item := range list {
+		if s, ok := item.(string); ok {
+			values = append(values, s)
+		}
+	}
+	return values
+}
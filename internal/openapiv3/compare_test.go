@@ -0,0 +1,165 @@
+package openapiv3_test
+
+import (
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+const compareBaseDoc = `
+openapi: 3.1.0
+paths:
+  /users/{id}:
+    get:
+      operationId: GetUser
+      parameters:
+        - name: id
+          in: path
+          required: true
+    delete:
+      operationId: DeleteUser
+components:
+  schemas:
+    Address:
+      type: object
+    User:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: string
+        age:
+          type: integer
+          format: int32
+        status:
+          type: string
+          enum: [ACTIVE, BANNED]
+        tags:
+          type: array
+          items:
+            type: string
+        address:
+          $ref: '#/components/schemas/Address'
+`
+
+func TestCompareDocuments(t *testing.T) {
+	tests := []struct {
+		name    string
+		replace [][2]string
+		want    []string
+	}{
+		{
+			name: "unchanged",
+		},
+		{
+			name:    "removed operation",
+			replace: [][2]string{{"    delete:\n      operationId: DeleteUser\n", ""}},
+			want:    []string{"removed operation: paths /users/{id} delete"},
+		},
+		{
+			name: "new required parameter",
+			replace: [][2]string{{"          required: true\n",
+				"          required: true\n        - name: tenant\n          in: query\n          required: true\n"}},
+			want: []string{"new required parameter: paths /users/{id} get: query tenant"},
+		},
+		{
+			name:    "removed schema",
+			replace: [][2]string{{"    Address:\n      type: object\n", ""}},
+			want:    []string{"removed schema: Address"},
+		},
+		{
+			name:    "removed property",
+			replace: [][2]string{{"        id:\n          type: string\n", ""}},
+			want:    []string{"removed property: User.id"},
+		},
+		{
+			name:    "narrowed type",
+			replace: [][2]string{{"type: integer\n          format: int32", "type: string\n          format: int64"}},
+			want: []string{
+				`narrowed type: User.age: format "int32" is now "int64"`,
+				`narrowed type: User.age: type "integer" no longer allowed`,
+			},
+		},
+		{
+			name:    "removed enum value",
+			replace: [][2]string{{"[ACTIVE, BANNED]", "[ACTIVE]"}},
+			want:    []string{"narrowed type: User.status: enum value BANNED removed"},
+		},
+		{
+			name: "added enum",
+			replace: [][2]string{{"        id:\n          type: string\n",
+				"        id:\n          type: string\n          enum: [a, b]\n"}},
+			want: []string{"narrowed type: User.id: enum constraint added"},
+		},
+		{
+			name:    "array items",
+			replace: [][2]string{{"items:\n            type: string", "items:\n            type: integer"}},
+			want:    []string{`narrowed type: User.tags[]: type "string" no longer allowed`},
+		},
+		{
+			name:    "new required field",
+			replace: [][2]string{{"required: [id]", "required: [id, age]"}},
+			want:    []string{"new required field: User.age"},
+		},
+		{
+			name:    "widening is not breaking",
+			replace: [][2]string{{"[ACTIVE, BANNED]", "[ACTIVE, BANNED, PENDING]"}, {"required: [id]", "required: []"}},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			current := compareBaseDoc
+			for _, r := range tt.replace {
+				if !strings.Contains(current, r[0]) {
+					t.Fatalf("test document does not contain %q", r[0])
+				}
+				current = strings.Replace(current, r[0], r[1], 1)
+			}
+			changes, err := openapiv3.CompareDocuments([]byte(compareBaseDoc), []byte(current))
+			if err != nil {
+				t.Fatalf("CompareDocuments() error = %v", err)
+			}
+			if !slices.Equal(changes, tt.want) {
+				t.Errorf("CompareDocuments() = %q, want %q", changes, tt.want)
+			}
+		})
+	}
+}
+
+func TestCompareGeneratedDocuments(t *testing.T) {
+	render := func(t *testing.T, withName bool) []byte {
+		t.Helper()
+		file := userServiceFile(nil)
+		if !withName {
+			file.MessageType[1] = descMessage("User", descStringField("id", 1))
+		}
+		gen := generateService(t, buildDescPlugin(t, file), "UserService")
+		data, err := gen.RenderAs(openapiv3.FormatJSON)
+		if err != nil {
+			t.Fatalf("RenderAs() error = %v", err)
+		}
+		return data
+	}
+
+	changes, err := openapiv3.CompareDocuments(render(t, true), render(t, false))
+	if err != nil {
+		t.Fatalf("CompareDocuments() error = %v", err)
+	}
+	if want := []string{"removed property: User.name"}; !slices.Equal(changes, want) {
+		t.Errorf("CompareDocuments() = %q, want %q", changes, want)
+	}
+
+	if changes, _ = openapiv3.CompareDocuments(render(t, false), render(t, true)); len(changes) > 0 {
+		t.Errorf("adding a property reported %q, want no breaking changes", changes)
+	}
+}
+
+func TestCompareDocumentsInvalidInput(t *testing.T) {
+	_, err := openapiv3.CompareDocuments([]byte("paths: [unterminated"), []byte(compareBaseDoc))
+	if err == nil || !strings.Contains(err.Error(), "previous OpenAPI document") {
+		t.Errorf("CompareDocuments() error = %v, want parse error", err)
+	}
+}
@@ -0,0 +1,209 @@
+package openapiv3_test
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/pluginpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+// --- protogen harness -------------------------------------------------------
+//
+// These helpers compile hand-built FileDescriptorProtos into a *protogen.Plugin so
+// generator behavior can be asserted on the resulting document without a protoc
+// binary. sebuf.http options are attached with proto.SetExtension.
+//
+// Output a .proto file can express belongs in a testdata/proto fixture with golden
+// files (see TESTING.md). Use these helpers for tests that call Generator methods
+// directly or need input protoc cannot produce.
+
+const descTestPkg = "test.openapi.v1"
+
+// descJSONName mirrors protoc's default lowerCamelCase json_name derivation.
+func descJSONName(name string) string {
+	parts := strings.Split(name, "_")
+	for i, part := range parts {
+		if i > 0 && len(part) > 0 {
+			parts[i] = strings.ToUpper(part[:1]) + part[1:]
+		}
+	}
+	return strings.Join(parts, "")
+}
+
+// descField builds a singular field descriptor of the given scalar type.
+func descField(
+	name string,
+	number int32,
+	typ descriptorpb.FieldDescriptorProto_Type,
+) *descriptorpb.FieldDescriptorProto {
+	return &descriptorpb.FieldDescriptorProto{
+		Name:     proto.String(name),
+		Number:   proto.Int32(number),
+		Label:    descriptorpb.FieldDescriptorProto_LABEL_OPTIONAL.Enum(),
+		Type:     typ.Enum(),
+		JsonName: proto.String(descJSONName(name)),
+	}
+}
+
+// descStringField builds a singular string field descriptor.
+func descStringField(name string, number int32) *descriptorpb.FieldDescriptorProto {
+	return descField(name, number, descriptorpb.FieldDescriptorProto_TYPE_STRING)
+}
+
+// descMessageField builds a singular field referencing a message by fully-qualified
+// name without the leading dot (e.g. "test.openapi.v1.User").
+func descMessageField(name string, number int32, fullName string) *descriptorpb.FieldDescriptorProto {
+	field := descField(name, number, descriptorpb.FieldDescriptorProto_TYPE_MESSAGE)
+	field.TypeName = proto.String("." + fullName)
+	return field
+}
+
+// descMessage builds a message descriptor with the given fields.
+func descMessage(name string, fields ...*descriptorpb.FieldDescriptorProto) *descriptorpb.DescriptorProto {
+	return &descriptorpb.DescriptorProto{
+		Name:  proto.String(name),
+		Field: fields,
+	}
+}
+
+// descMethod builds an RPC whose input/output are messages in descTestPkg.
+func descMethod(name, input, output string) *descriptorpb.MethodDescriptorProto {
+	return &descriptorpb.MethodDescriptorProto{
+		Name:       proto.String(name),
+		InputType:  proto.String("." + descTestPkg + "." + input),
+		OutputType: proto.String("." + descTestPkg + "." + output),
+	}
+}
+
+// withHTTPConfig attaches a sebuf.http.config annotation to the method.
+func withHTTPConfig(
+	method *descriptorpb.MethodDescriptorProto,
+	config *http.HttpConfig,
+) *descriptorpb.MethodDescriptorProto {
+	if method.Options == nil {
+		method.Options = &descriptorpb.MethodOptions{}
+	}
+	proto.SetExtension(method.Options, http.E_Config, config)
+	return method
+}
+
+// descService builds a service descriptor, optionally carrying a service_config.
+func descService(
+	name string,
+	config *http.ServiceConfig,
+	methods ...*descriptorpb.MethodDescriptorProto,
+) *descriptorpb.ServiceDescriptorProto {
+	service := &descriptorpb.ServiceDescriptorProto{
+		Name:   proto.String(name),
+		Method: methods,
+	}
+	if config != nil {
+		service.Options = &descriptorpb.ServiceOptions{}
+		proto.SetExtension(service.Options, http.E_ServiceConfig, config)
+	}
+	return service
+}
+
+// descFile builds a proto3 file in the given package.
+func descFile(
+	name, pkg string,
+	messages []*descriptorpb.DescriptorProto,
+	services ...*descriptorpb.ServiceDescriptorProto,
+) *descriptorpb.FileDescriptorProto {
+	return &descriptorpb.FileDescriptorProto{
+		Name:    proto.String(name),
+		Package: proto.String(pkg),
+		Syntax:  proto.String("proto3"),
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("github.com/SebastienMelki/sebuf/internal/openapiv3/" +
+				strings.ReplaceAll(pkg, ".", "")),
+		},
+		MessageType: messages,
+		Service:     services,
+	}
+}
+
+// buildDescPlugin compiles the files into a plugin; every file is marked for generation.
+func buildDescPlugin(t *testing.T, files ...*descriptorpb.FileDescriptorProto) *protogen.Plugin {
+	t.Helper()
+	req := &pluginpb.CodeGeneratorRequest{ProtoFile: files}
+	for _, f := range files {
+		req.FileToGenerate = append(req.FileToGenerate, f.GetName())
+	}
+	plugin, err := protogen.Options{}.New(req)
+	if err != nil {
+		t.Fatalf("protogen.Options{}.New: %v", err)
+	}
+	return plugin
+}
+
+// findDescService returns the named service from the plugin's files.
+func findDescService(t *testing.T, plugin *protogen.Plugin, name string) *protogen.Service {
+	t.Helper()
+	for _, file := range plugin.Files {
+		for _, service := range file.Services {
+			if string(service.Desc.Name()) == name {
+				return service
+			}
+		}
+	}
+	t.Fatalf("service %q not found in plugin", name)
+	return nil
+}
+
+// generateService runs the per-service generation flow used by protoc-gen-openapiv3.
+func generateService(t *testing.T, plugin *protogen.Plugin, serviceName string) *openapiv3.Generator {
+	t.Helper()
+	service := findDescService(t, plugin, serviceName)
+	gen := openapiv3.NewGenerator(openapiv3.FormatYAML)
+	gen.CollectReferencedMessages(service)
+	gen.ProcessService(service)
+	return gen
+}
+
+// userServiceFile builds a file with a single GetUser RPC on UserService.
+func userServiceFile(config *http.ServiceConfig) *descriptorpb.FileDescriptorProto {
+	return descFile("user.proto", descTestPkg,
+		[]*descriptorpb.DescriptorProto{
+			descMessage("GetUserRequest", descStringField("id", 1)),
+			descMessage("User", descStringField("id", 1), descStringField("name", 2)),
+		},
+		descService("UserService", config,
+			withHTTPConfig(descMethod("GetUser", "GetUserRequest", "User"), &http.HttpConfig{
+				Path:   "/users/{id}",
+				Method: http.HttpMethod_HTTP_METHOD_GET,
+			}),
+		),
+	)
+}
+
+// statusEnumFile builds a file whose User message has a status field of the Status enum.
+// ACTIVE carries a leading comment so value descriptions can be asserted.
+func statusEnumFile() *descriptorpb.FileDescriptorProto {
+	status := descField("status", 2, descriptorpb.FieldDescriptorProto_TYPE_ENUM)
+	status.TypeName = proto.String("." + descTestPkg + ".Status")
+
+	file := userServiceFile(nil)
+	file.MessageType[1] = descMessage("User", descStringField("id", 1), status)
+	file.EnumType = []*descriptorpb.EnumDescriptorProto{{
+		Name: proto.String("Status"),
+		Value: []*descriptorpb.EnumValueDescriptorProto{
+			{Name: proto.String("STATUS_UNSPECIFIED"), Number: proto.Int32(0)},
+			{Name: proto.String("STATUS_ACTIVE"), Number: proto.Int32(1)},
+		},
+	}}
+	file.SourceCodeInfo = &descriptorpb.SourceCodeInfo{
+		Location: []*descriptorpb.SourceCodeInfo_Location{{
+			Path:            []int32{5, 0, 2, 1}, // enum_type[0].value[1]
+			Span:            []int32{0, 0, 0},
+			LeadingComments: proto.String(" The user can sign in.\n"),
+		}},
+	}
+	return file
+}
@@ -0,0 +1,223 @@
+package openapiv3
+
+import (
+	"fmt"
+	"slices"
+	"strings"
+
+	yaml "go.yaml.in/yaml/v4"
+)
+
+// operationMethods are the path item keys holding operations.
+//
+//nolint:gochecknoglobals // read-only list of OpenAPI operation keys
+var operationMethods = []string{"get", "put", "post", "delete", "options", "head", "patch", "trace"}
+
+// CheckDocument runs structural checks on a rendered OpenAPI document (YAML or JSON) and
+// returns one message per problem found:
+//   - a $ref that does not resolve within the document
+//   - an operationId used by more than one operation, in paths or webhooks
+//   - a malformed path template, a template variable without a matching path
+//     parameter, or a path parameter missing from the template
+//
+// It returns nil for a document without problems.
+func CheckDocument(data []byte) ([]string, error) {
+	var doc map[string]any
+	if err := yaml.Unmarshal(data, &doc); err != nil {
+		return nil, fmt.Errorf("failed to parse OpenAPI document: %w", err)
+	}
+
+	var problems []string
+	problems = append(problems, checkRefs(doc, doc, "#")...)
+	problems = append(problems, checkPaths(doc)...)
+	return problems, nil
+}
+
+// checkRefs reports every $ref under node that does not resolve against doc. location is
+// the JSON pointer of node, so each problem names exactly where the reference sits.
+func checkRefs(doc map[string]any, node any, location string) []string {
+	var problems []string
+	switch value := node.(type) {
+	case map[string]any:
+		for _, key := range sortedKeys(value) {
+			child := value[key]
+			if ref, ok := child.(string); ok && key == "$ref" {
+				if !resolvesRef(doc, ref) {
+					problems = append(problems, fmt.Sprintf("%s: unresolved $ref %q", location, ref))
+				}
+				continue
+			}
+			problems = append(problems, checkRefs(doc, child, location+"/"+escapePointerToken(key))...)
+		}
+	case []any:
+		for i, child := range value {
+			problems = append(problems, checkRefs(doc, child, fmt.Sprintf("%s/%d", location, i))...)
+		}
+	}
+	return problems
+}
+
+// resolvesRef reports whether a local reference such as "#/components/schemas/User"
+// points at an existing node. External references are not followed.
+func resolvesRef(doc map[string]any, ref string) bool {
+	pointer, ok := strings.CutPrefix(ref, "#")
+	if !ok {
+		return true
+	}
+
+	var node any = doc
+	for _, token := range strings.Split(strings.TrimPrefix(pointer, "/"), "/") {
+		if token == "" && pointer == "" {
+			break
+		}
+		token = unescapePointerToken(token)
+		object, isObject := node.(map[string]any)
+		if !isObject {
+			return false
+		}
+		if node, ok = object[token]; !ok {
+			return false
+		}
+	}
+	return true
+}
+
+// escapePointerToken escapes a key for use as a JSON pointer token (RFC 6901).
+func escapePointerToken(token string) string {
+	return strings.ReplaceAll(strings.ReplaceAll(token, "~", "~0"), "/", "~1")
+}
+
+// unescapePointerToken reverses escapePointerToken.
+func unescapePointerToken(token string) string {
+	return strings.ReplaceAll(strings.ReplaceAll(token, "~1", "/"), "~0", "~")
+}
+
+// checkPaths checks operationId uniqueness across paths and webhooks, and path templates
+// against path parameters.
+func checkPaths(doc map[string]any) []string {
+	paths, _ := doc["paths"].(map[string]any)
+
+	var problems []string
+	operationIDs := make(map[string]string)
+	checkOperationID := func(location string, operation map[string]any) {
+		id, _ := operation["operationId"].(string)
+		if id == "" {
+			return
+		}
+		if previous, taken := operationIDs[id]; taken {
+			problems = append(problems, fmt.Sprintf("%s: operationId %q is already used by %s", location, id, previous))
+		} else {
+			operationIDs[id] = location
+		}
+	}
+
+	for _, path := range sortedKeys(paths) {
+		pathItem, _ := paths[path].(map[string]any)
+
+		templateParams, err := pathTemplateParams(path)
+		if err != nil {
+			problems = append(problems, fmt.Sprintf("paths %s: %v", path, err))
+		}
+
+		for _, method := range operationMethods {
+			operation, ok := pathItem[method].(map[string]any)
+			if !ok {
+				continue
+			}
+			location := fmt.Sprintf("paths %s %s", path, method)
+			checkOperationID(location, operation)
+
+			if err == nil {
+				declared := pathParameterNames(pathItem["parameters"], operation["parameters"])
+				problems = append(problems, comparePathParams(location, templateParams, declared)...)
+			}
+		}
+	}
+
+	webhooks, _ := doc["webhooks"].(map[string]any)
+	for _, name := range sortedKeys(webhooks) {
+		pathItem, _ := webhooks[name].(map[string]any)
+		for _, method := range operationMethods {
+			if operation, ok := pathItem[method].(map[string]any); ok {
+				checkOperationID(fmt.Sprintf("webhooks %s %s", name, method), operation)
+			}
+		}
+	}
+	return problems
+}
+
+// pathTemplateParams returns the variable names of a path template such as
+// "/users/{id}", rejecting unbalanced braces, empty names and repeated names.
+func pathTemplateParams(path string) ([]string, error) {
+	var params []string
+	rest := path
+	for {
+		open := strings.IndexAny(rest, "{}")
+		if open < 0 {
+			return params, nil
+		}
+		if rest[open] == '}' {
+			return nil, fmt.Errorf("unbalanced '}' in path template")
+		}
+		closing := strings.IndexAny(rest[open+1:], "{}")
+		if closing < 0 || rest[open+1+closing] == '{' {
+			return nil, fmt.Errorf("unbalanced '{' in path template")
+		}
+		name := rest[open+1 : open+1+closing]
+		if name == "" {
+			return nil, fmt.Errorf("empty path template variable")
+		}
+		if slices.Contains(params, name) {
+			return nil, fmt.Errorf("path template variable %q appears more than once", name)
+		}
+		params = append(params, name)
+		rest = rest[open+closing+2:]
+	}
+}
+
+// pathParameterNames collects the names of `in: path` parameters from parameter lists.
+func pathParameterNames(lists ...any) []string {
+	var names []string
+	for _, list := range lists {
+		parameters, _ := list.([]any)
+		for _, parameter := range parameters {
+			fields, _ := parameter.(map[string]any)
+			if in, _ := fields["in"].(string); in != "path" {
+				continue
+			}
+			if name, _ := fields["name"].(string); name != "" && !slices.Contains(names, name) {
+				names = append(names, name)
+			}
+		}
+	}
+	return names
+}
+
+// comparePathParams reports template variables without a path parameter and path
+// parameters that do not appear in the template.
+func comparePathParams(location string, template, declared []string) []string {
+	var problems []string
+	for _, name := range template {
+		if !slices.Contains(declared, name) {
+			problems = append(problems,
+				fmt.Sprintf("%s: path template variable %q has no path parameter", location, name))
+		}
+	}
+	for _, name := range declared {
+		if !slices.Contains(template, name) {
+			problems = append(problems,
+				fmt.Sprintf("%s: path parameter %q does not appear in the path template", location, name))
+		}
+	}
+	return problems
+}
+
+// sortedKeys returns the keys of m in lexical order so problems are reported stably.
+func sortedKeys(m map[string]any) []string {
+	keys := make([]string, 0, len(m))
+	for key := range m {
+		keys = append(keys, key)
+	}
+	slices.Sort(keys)
+	return keys
+}
@@ -0,0 +1,162 @@
+package openapiv3_test
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+func TestCheckDocumentGeneratedService(t *testing.T) {
+	plugin := buildDescPlugin(t, userServiceFile(nil))
+	gen := generateService(t, plugin, "UserService")
+
+	for _, format := range []openapiv3.OutputFormat{openapiv3.FormatYAML, openapiv3.FormatJSON} {
+		data, err := gen.RenderAs(format)
+		if err != nil {
+			t.Fatalf("RenderAs(%v) error = %v", format, err)
+		}
+		problems, err := openapiv3.CheckDocument(data)
+		if err != nil {
+			t.Fatalf("CheckDocument() error = %v", err)
+		}
+		if len(problems) > 0 {
+			t.Errorf("CheckDocument(%v) problems = %v, want none", format, problems)
+		}
+	}
+}
+
+func TestCheckDocumentProblems(t *testing.T) {
+	tests := []struct {
+		name string
+		doc  string
+		want string
+	}{
+		{
+			name: "unresolved ref",
+			doc: `
+openapi: 3.1.0
+paths:
+  /users:
+    get:
+      operationId: ListUsers
+      responses:
+        "200":
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Missing'
+components:
+  schemas: {}
+`,
+			want: "#/paths/~1users/get/responses/200/content/application~1json/schema: " +
+				`unresolved $ref "#/components/schemas/Missing"`,
+		},
+		{
+			name: "duplicate operationId",
+			doc: `
+openapi: 3.1.0
+paths:
+  /a:
+    get:
+      operationId: Fetch
+  /b:
+    get:
+      operationId: Fetch
+`,
+			want: `paths /b get: operationId "Fetch" is already used by paths /a get`,
+		},
+		{
+			name: "operationId shared by a path and a webhook",
+			doc: `
+openapi: 3.1.0
+paths:
+  /users:
+    post:
+      operationId: UserCreated
+webhooks:
+  UserCreated:
+    post:
+      operationId: UserCreated
+`,
+			want: `webhooks UserCreated post: operationId "UserCreated" is already used by paths /users post`,
+		},
+		{
+			name: "template variable without parameter",
+			doc: `
+openapi: 3.1.0
+paths:
+  /users/{id}:
+    get:
+      operationId: GetUser
+`,
+			want: `paths /users/{id} get: path template variable "id" has no path parameter`,
+		},
+		{
+			name: "path parameter missing from template",
+			doc: `
+openapi: 3.1.0
+paths:
+  /users:
+    get:
+      operationId: GetUser
+      parameters:
+        - name: id
+          in: path
+          required: true
+`,
+			want: `paths /users get: path parameter "id" does not appear in the path template`,
+		},
+		{
+			name: "unbalanced brace",
+			doc: `
+openapi: 3.1.0
+paths:
+  /users/{id:
+    get:
+      operationId: GetUser
+`,
+			want: `paths /users/{id: unbalanced '{' in path template`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			problems, err := openapiv3.CheckDocument([]byte(tt.doc))
+			if err != nil {
+				t.Fatalf("CheckDocument() error = %v", err)
+			}
+			if len(problems) != 1 || problems[0] != tt.want {
+				t.Errorf("CheckDocument() problems = %q, want [%q]", problems, tt.want)
+			}
+		})
+	}
+}
+
+func TestCheckDocumentPathItemParameters(t *testing.T) {
+	doc := `
+openapi: 3.1.0
+paths:
+  /users/{id}:
+    parameters:
+      - name: id
+        in: path
+        required: true
+    get:
+      operationId: GetUser
+`
+	problems, err := openapiv3.CheckDocument([]byte(doc))
+	if err != nil {
+		t.Fatalf("CheckDocument() error = %v", err)
+	}
+	if len(problems) > 0 {
+		t.Errorf("CheckDocument() problems = %v, want none", problems)
+	}
+}
+
+func TestCheckDocumentInvalidInput(t *testing.T) {
+	_, err := openapiv3.CheckDocument([]byte("paths: [unterminated"))
+	if err == nil || !strings.Contains(err.Error(), "failed to parse OpenAPI document") {
+		t.Errorf("CheckDocument() error = %v, want parse error", err)
+	}
+}
@@ -0,0 +1,98 @@
+package openapiv3
+
+import (
+	"github.com/pb33f/libopenapi/datamodel/high/base"
+	v3 "github.com/pb33f/libopenapi/datamodel/high/v3"
+	"github.com/pb33f/libopenapi/orderedmap"
+	yaml "go.yaml.in/yaml/v4"
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
+
+	"github.com/SebastienMelki/sebuf/internal/annotations"
+)
+
+// SetBodyExamples enables example request and response bodies assembled from field
+// examples. It must be called before any service is processed.
+func (g *Generator) SetBodyExamples(enabled bool) {
+	g.bodyExamples = enabled
+}
+
+// addBodyExample attaches an example of message, assembled from field examples, to a JSON
+// media type. The example is named after the message's schema.
+func (g *Generator) addBodyExample(mediaType *v3.MediaType, message *protogen.Message) {
+	if !g.bodyExamples {
+		return
+	}
+	example := buildMessageExample(message, make(map[protoreflect.FullName]bool))
+	if example == nil {
+		return
+	}
+	mediaType.Examples = orderedmap.New[string, *base.Example]()
+	mediaType.Examples.Set(g.getSchemaName(message), &base.Example{
+		Summary: "Assembled from field examples",
+		Value:   example,
+	})
+}
+
+// buildMessageExample assembles a JSON object for message from the first example of each
+// field, descending into message fields; only the first member of a oneof with an
+// example is used. It returns nil when no field yields an example, for a message already
+// being assembled (a recursive reference), and for messages whose JSON shape is
+// customized by root unwrap, flattened fields or discriminated oneofs.
+func buildMessageExample(message *protogen.Message, visiting map[protoreflect.FullName]bool) *yaml.Node {
+	name := message.Desc.FullName()
+	if visiting[name] || hasCustomJSONShape(message) {
+		return nil
+	}
+	visiting[name] = true
+	defer delete(visiting, name)
+
+	object := &yaml.Node{Kind: yaml.MappingNode, Tag: "!!map"}
+	usedOneofs := make(map[protoreflect.FullName]bool)
+	for _, field := range message.Fields {
+		if field.Desc.IsMap() {
+			continue
+		}
+		oneof := field.Desc.ContainingOneof()
+		if oneof != nil && usedOneofs[oneof.FullName()] {
+			continue
+		}
+		value := buildFieldExample(field, visiting)
+		if value == nil {
+			continue
+		}
+		if oneof != nil {
+			usedOneofs[oneof.FullName()] = true
+		}
+		object.Content = append(object.Content, &yaml.Node{Kind: yaml.ScalarNode, Value: field.Desc.JSONName()}, value)
+	}
+
+	if len(object.Content) == 0 {
+		return nil
+	}
+	return object
+}
+
+// buildFieldExample returns the example value of a field: its first field example, or
+// an assembled object for message fields. Repeated fields get a one-element list.
+func buildFieldExample(field *protogen.Field, visiting map[protoreflect.FullName]bool) *yaml.Node {
+	var value *yaml.Node
+	if examples := annotations.GetFieldExamples(field); len(examples) > 0 {
+		value = &yaml.Node{Kind: yaml.ScalarNode, Value: examples[0]}
+	} else if field.Message != nil && !annotations.IsWellKnownField(field) {
+		value = buildMessageExample(field.Message, visiting)
+	}
+
+	if value == nil || !field.Desc.IsList() {
+		return value
+	}
+	return &yaml.Node{Kind: yaml.SequenceNode, Tag: "!!seq", Content: []*yaml.Node{value}}
+}
+
+// hasCustomJSONShape reports whether message is not serialized as a plain object of its
+// fields, so an example assembled field by field would not match its schema.
+func hasCustomJSONShape(message *protogen.Message) bool {
+	return getRootUnwrapInfo(message) != nil ||
+		annotations.HasFlattenFields(message) ||
+		annotations.HasOneofDiscriminator(message)
+}
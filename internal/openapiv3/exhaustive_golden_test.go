@@ -143,6 +143,7 @@ func TestExhaustiveGoldenFiles(t *testing.T) {
 		serviceName string
 		goldenFile  string
 		format      string // "yaml" or "json"
+		options     string // extra plugin options, e.g. "enum_details=true"
 	}{
 		// simple_service.proto -> SimpleService
 		{
@@ -595,6 +596,271 @@ func TestExhaustiveGoldenFiles(t *testing.T) {
 			goldenFile:  "testdata/golden/json/EmptyRequestBodyService.openapi.json",
 			format:      "json",
 		},
+		// service_host.proto -> HostService (service_config host as the servers entry)
+		{
+			name:        "host_service_yaml",
+			protoFile:   "testdata/proto/service_host.proto",
+			serviceName: "HostService",
+			goldenFile:  "testdata/golden/yaml/HostService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "host_service_json",
+			protoFile:   "testdata/proto/service_host.proto",
+			serviceName: "HostService",
+			goldenFile:  "testdata/golden/json/HostService.openapi.json",
+			format:      "json",
+		},
+		// field_examples.proto -> FieldExamplesService (field_examples as example and examples)
+		{
+			name:        "field_examples_service_yaml",
+			protoFile:   "testdata/proto/field_examples.proto",
+			serviceName: "FieldExamplesService",
+			goldenFile:  "testdata/golden/yaml/FieldExamplesService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "field_examples_service_json",
+			protoFile:   "testdata/proto/field_examples.proto",
+			serviceName: "FieldExamplesService",
+			goldenFile:  "testdata/golden/json/FieldExamplesService.openapi.json",
+			format:      "json",
+		},
+		// deprecated_elements.proto -> DeprecationService (deprecated operations, fields and message fields)
+		{
+			name:        "deprecated_elements_yaml",
+			protoFile:   "testdata/proto/deprecated_elements.proto",
+			serviceName: "DeprecationService",
+			goldenFile:  "testdata/golden/yaml/DeprecationService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "deprecated_elements_json",
+			protoFile:   "testdata/proto/deprecated_elements.proto",
+			serviceName: "DeprecationService",
+			goldenFile:  "testdata/golden/json/DeprecationService.openapi.json",
+			format:      "json",
+		},
+		// method_responses.proto -> MethodResponseService (responses declared on the method)
+		{
+			name:        "method_responses_yaml",
+			protoFile:   "testdata/proto/method_responses.proto",
+			serviceName: "MethodResponseService",
+			goldenFile:  "testdata/golden/yaml/MethodResponseService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "method_responses_json",
+			protoFile:   "testdata/proto/method_responses.proto",
+			serviceName: "MethodResponseService",
+			goldenFile:  "testdata/golden/json/MethodResponseService.openapi.json",
+			format:      "json",
+		},
+		// enum_details.proto -> EnumDetailsService (enum_details=true)
+		{
+			name:        "enum_details_yaml",
+			protoFile:   "testdata/proto/enum_details.proto",
+			serviceName: "EnumDetailsService",
+			goldenFile:  "testdata/golden/yaml/EnumDetailsService.openapi.yaml",
+			format:      "yaml",
+			options:     "enum_details=true",
+		},
+		{
+			name:        "enum_details_json",
+			protoFile:   "testdata/proto/enum_details.proto",
+			serviceName: "EnumDetailsService",
+			goldenFile:  "testdata/golden/json/EnumDetailsService.openapi.json",
+			format:      "json",
+			options:     "enum_details=true",
+		},
+		// operation_extensions.proto -> ExtensionService (service and method openapi_extensions)
+		{
+			name:        "operation_extensions_yaml",
+			protoFile:   "testdata/proto/operation_extensions.proto",
+			serviceName: "ExtensionService",
+			goldenFile:  "testdata/golden/yaml/ExtensionService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "operation_extensions_json",
+			protoFile:   "testdata/proto/operation_extensions.proto",
+			serviceName: "ExtensionService",
+			goldenFile:  "testdata/golden/json/ExtensionService.openapi.json",
+			format:      "json",
+		},
+		// streaming_methods.proto -> StreamingService (server streaming without stream: true)
+		{
+			name:        "streaming_methods_yaml",
+			protoFile:   "testdata/proto/streaming_methods.proto",
+			serviceName: "StreamingService",
+			goldenFile:  "testdata/golden/yaml/StreamingService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "streaming_methods_json",
+			protoFile:   "testdata/proto/streaming_methods.proto",
+			serviceName: "StreamingService",
+			goldenFile:  "testdata/golden/json/StreamingService.openapi.json",
+			format:      "json",
+		},
+		// streaming_methods.proto -> SkippedStreamingService (streaming=skip)
+		{
+			name:        "streaming_methods_skip_yaml",
+			protoFile:   "testdata/proto/streaming_methods.proto",
+			serviceName: "SkippedStreamingService",
+			goldenFile:  "testdata/golden/yaml/SkippedStreamingService.openapi.yaml",
+			format:      "yaml",
+			options:     "streaming=skip",
+		},
+		{
+			name:        "streaming_methods_skip_json",
+			protoFile:   "testdata/proto/streaming_methods.proto",
+			serviceName: "SkippedStreamingService",
+			goldenFile:  "testdata/golden/json/SkippedStreamingService.openapi.json",
+			format:      "json",
+			options:     "streaming=skip",
+		},
+		// operation_tags.proto -> TaggedService (service and method tags)
+		{
+			name:        "operation_tags_yaml",
+			protoFile:   "testdata/proto/operation_tags.proto",
+			serviceName: "TaggedService",
+			goldenFile:  "testdata/golden/yaml/TaggedService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "operation_tags_json",
+			protoFile:   "testdata/proto/operation_tags.proto",
+			serviceName: "TaggedService",
+			goldenFile:  "testdata/golden/json/TaggedService.openapi.json",
+			format:      "json",
+		},
+		// webhooks.proto -> WebhookService (webhook: true methods)
+		{
+			name:        "webhooks_yaml",
+			protoFile:   "testdata/proto/webhooks.proto",
+			serviceName: "WebhookService",
+			goldenFile:  "testdata/golden/yaml/WebhookService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "webhooks_json",
+			protoFile:   "testdata/proto/webhooks.proto",
+			serviceName: "WebhookService",
+			goldenFile:  "testdata/golden/json/WebhookService.openapi.json",
+			format:      "json",
+		},
+		// recursive_messages.proto -> CommentService (self-referencing and mutually recursive messages)
+		{
+			name:        "recursive_messages_yaml",
+			protoFile:   "testdata/proto/recursive_messages.proto",
+			serviceName: "CommentService",
+			goldenFile:  "testdata/golden/yaml/CommentService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "recursive_messages_json",
+			protoFile:   "testdata/proto/recursive_messages.proto",
+			serviceName: "CommentService",
+			goldenFile:  "testdata/golden/json/CommentService.openapi.json",
+			format:      "json",
+		},
+		// protobuf_media_type.proto -> ProtobufMediaService (protobuf_media_type=true)
+		{
+			name:        "protobuf_media_type_yaml",
+			protoFile:   "testdata/proto/protobuf_media_type.proto",
+			serviceName: "ProtobufMediaService",
+			goldenFile:  "testdata/golden/yaml/ProtobufMediaService.openapi.yaml",
+			format:      "yaml",
+			options:     "protobuf_media_type=true",
+		},
+		{
+			name:        "protobuf_media_type_json",
+			protoFile:   "testdata/proto/protobuf_media_type.proto",
+			serviceName: "ProtobufMediaService",
+			goldenFile:  "testdata/golden/json/ProtobufMediaService.openapi.json",
+			format:      "json",
+			options:     "protobuf_media_type=true",
+		},
+		// body_examples.proto -> BodyExamplesService (body_examples=true)
+		{
+			name:        "body_examples_yaml",
+			protoFile:   "testdata/proto/body_examples.proto",
+			serviceName: "BodyExamplesService",
+			goldenFile:  "testdata/golden/yaml/BodyExamplesService.openapi.yaml",
+			format:      "yaml",
+			options:     "body_examples=true",
+		},
+		{
+			name:        "body_examples_json",
+			protoFile:   "testdata/proto/body_examples.proto",
+			serviceName: "BodyExamplesService",
+			goldenFile:  "testdata/golden/json/BodyExamplesService.openapi.json",
+			format:      "json",
+			options:     "body_examples=true",
+		},
+		// proto2_defaults.proto -> DefaultsService (proto2 field defaults)
+		{
+			name:        "proto2_defaults_yaml",
+			protoFile:   "testdata/proto/proto2_defaults.proto",
+			serviceName: "DefaultsService",
+			goldenFile:  "testdata/golden/yaml/DefaultsService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "proto2_defaults_json",
+			protoFile:   "testdata/proto/proto2_defaults.proto",
+			serviceName: "DefaultsService",
+			goldenFile:  "testdata/golden/json/DefaultsService.openapi.json",
+			format:      "json",
+		},
+		// imported_schemas.proto -> ShipmentService (messages from an imported file that is not generated)
+		{
+			name:        "imported_schemas_yaml",
+			protoFile:   "testdata/proto/imported_schemas.proto",
+			serviceName: "ShipmentService",
+			goldenFile:  "testdata/golden/yaml/ShipmentService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "imported_schemas_json",
+			protoFile:   "testdata/proto/imported_schemas.proto",
+			serviceName: "ShipmentService",
+			goldenFile:  "testdata/golden/json/ShipmentService.openapi.json",
+			format:      "json",
+		},
+		// well_known_types.proto -> WellKnownTypesService (well-known type fields described inline)
+		{
+			name:        "well_known_types_yaml",
+			protoFile:   "testdata/proto/well_known_types.proto",
+			serviceName: "WellKnownTypesService",
+			goldenFile:  "testdata/golden/yaml/WellKnownTypesService.openapi.yaml",
+			format:      "yaml",
+		},
+		{
+			name:        "well_known_types_json",
+			protoFile:   "testdata/proto/well_known_types.proto",
+			serviceName: "WellKnownTypesService",
+			goldenFile:  "testdata/golden/json/WellKnownTypesService.openapi.json",
+			format:      "json",
+		},
+		// enum_aliases.proto -> EnumAliasService (allow_alias with enum_details=true)
+		{
+			name:        "enum_aliases_yaml",
+			protoFile:   "testdata/proto/enum_aliases.proto",
+			serviceName: "EnumAliasService",
+			goldenFile:  "testdata/golden/yaml/EnumAliasService.openapi.yaml",
+			format:      "yaml",
+			options:     "enum_details=true",
+		},
+		{
+			name:        "enum_aliases_json",
+			protoFile:   "testdata/proto/enum_aliases.proto",
+			serviceName: "EnumAliasService",
+			goldenFile:  "testdata/golden/json/EnumAliasService.openapi.json",
+			format:      "json",
+			options:     "enum_details=true",
+		},
 	}
 
 	for _, tc := range testCases {
@@ -604,6 +870,9 @@ func TestExhaustiveGoldenFiles(t *testing.T) {
 
 			// Set format parameter
 			formatParam := fmt.Sprintf("format=%s", tc.format)
+			if tc.options != "" {
+				formatParam += "," + tc.options
+			}
 
 			// Run protoc to generate new output
 			cmd := exec.Command("protoc",
@@ -701,6 +970,18 @@ func TestExhaustiveRegression(t *testing.T) {
 		"testdata/proto/flatten.proto":                  {"FlattenService"},
 		"testdata/proto/oneof_discriminator.proto":      {"OneofDiscriminatorService"},
 		"testdata/proto/sse.proto":                      {"SSEService"},
+		"testdata/proto/service_host.proto":             {"HostService"},
+		"testdata/proto/field_examples.proto":           {"FieldExamplesService"},
+		"testdata/proto/deprecated_elements.proto":      {"DeprecationService"},
+		"testdata/proto/method_responses.proto":         {"MethodResponseService"},
+		"testdata/proto/operation_extensions.proto":     {"ExtensionService"},
+		"testdata/proto/streaming_methods.proto":        {"StreamingService"},
+		"testdata/proto/operation_tags.proto":           {"TaggedService"},
+		"testdata/proto/webhooks.proto":                 {"WebhookService"},
+		"testdata/proto/recursive_messages.proto":       {"CommentService"},
+		"testdata/proto/proto2_defaults.proto":          {"DefaultsService"},
+		"testdata/proto/imported_schemas.proto":         {"ShipmentService"},
+		"testdata/proto/well_known_types.proto":         {"WellKnownTypesService"},
 	}
 
 	formats := []string{"yaml", "json"}
@@ -2,6 +2,9 @@ package openapiv3
 
 import (
 	"fmt"
+	stdhttp "net/http"
+	"slices"
+	"strconv"
 	"strings"
 
 	"google.golang.org/protobuf/proto"
@@ -11,6 +14,8 @@ import (
 	"github.com/pb33f/libopenapi/orderedmap"
 	yaml "go.yaml.in/yaml/v4"
 	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/types/descriptorpb"
 	k8syaml "sigs.k8s.io/yaml"
 
 	"github.com/SebastienMelki/sebuf/internal/annotations"
@@ -24,6 +29,63 @@ const (
 	FormatJSON OutputFormat = "json"
 )
 
+// SchemaNaming selects how component schema names are derived from protobuf messages.
+// The same name is used for the components.schemas key and every $ref to it.
+type SchemaNaming string
+
+const (
+	// SchemaNamingShort uses the bare message name (e.g. "User"). Default for per-service output.
+	SchemaNamingShort SchemaNaming = "short"
+	// SchemaNamingPackagePrefixed joins the fully-qualified name with underscores
+	// (e.g. "acme_users_v1_User"). Default for bundle output.
+	SchemaNamingPackagePrefixed SchemaNaming = "package_prefixed"
+	// SchemaNamingFQN uses the dotted fully-qualified name (e.g. "acme.users.v1.User").
+	SchemaNamingFQN SchemaNaming = "fqn"
+	// SchemaNamingShortDedup uses the bare message name unless it is already taken by another
+	// message or a built-in error schema, in which case the package-prefixed name is used.
+	SchemaNamingShortDedup SchemaNaming = "short_dedup"
+)
+
+// ParseSchemaNaming validates a schema_naming plugin parameter value.
+func ParseSchemaNaming(value string) (SchemaNaming, error) {
+	switch naming := SchemaNaming(value); naming {
+	case SchemaNamingShort, SchemaNamingPackagePrefixed, SchemaNamingFQN, SchemaNamingShortDedup:
+		return naming, nil
+	}
+	return "", fmt.Errorf(
+		"invalid schema_naming %q: must be one of short, package_prefixed, fqn, short_dedup", value)
+}
+
+// OperationIDNaming selects how operationIds are derived from RPCs.
+type OperationIDNaming string
+
+const (
+	// OperationIDNamingMethod uses the RPC name (e.g. "GetUser"). Default.
+	OperationIDNamingMethod OperationIDNaming = "method"
+	// OperationIDNamingServiceMethod joins the service and RPC names (e.g. "UserService_GetUser").
+	OperationIDNamingServiceMethod OperationIDNaming = "service_method"
+	// OperationIDNamingLowerCamel lowercases the first letter of the RPC name (e.g. "getUser").
+	OperationIDNamingLowerCamel OperationIDNaming = "lower_camel"
+	// OperationIDNamingFQN uses the fully-qualified RPC name
+	// (e.g. "acme.users.v1.UserService.GetUser").
+	OperationIDNamingFQN OperationIDNaming = "fqn"
+)
+
+// ParseOperationIDNaming validates an operation_id_naming plugin parameter value.
+func ParseOperationIDNaming(value string) (OperationIDNaming, error) {
+	switch naming := OperationIDNaming(value); naming {
+	case OperationIDNamingMethod, OperationIDNamingServiceMethod, OperationIDNamingLowerCamel, OperationIDNamingFQN:
+		return naming, nil
+	}
+	return "", fmt.Errorf(
+		"invalid operation_id_naming %q: must be one of method, service_method, lower_camel, fqn", value)
+}
+
+// builtinSchemaNames are the schemas added by addBuiltinErrorSchemas.
+//
+//nolint:gochecknoglobals // read-only list of reserved schema names
+var builtinSchemaNames = []string{"Error", "ValidationError", "FieldViolation"}
+
 // HTTP method constants (lowercase for OpenAPI).
 const (
 	httpMethodGet    = "get"
@@ -39,6 +101,45 @@ type Generator struct {
 	schemas    *orderedmap.Map[string, *base.SchemaProxy]
 	format     OutputFormat
 	bundleMode bool
+
+	// schemaNaming overrides the mode's default naming strategy when non-empty.
+	schemaNaming SchemaNaming
+	// dedupNames and dedupOwners back SchemaNamingShortDedup: the name assigned to each
+	// message, and the message (or "" for built-ins) owning each taken name.
+	dedupNames  map[protoreflect.FullName]string
+	dedupOwners map[string]protoreflect.FullName
+	// operationIDNaming selects the operationId format; empty means OperationIDNamingMethod.
+	operationIDNaming OperationIDNaming
+
+	// routeOwners and operationIDOwners map each "METHOD /path" and operationId to the RPC
+	// that claimed it first; later claims are recorded in conflicts.
+	routeOwners       map[string]protoreflect.FullName
+	operationIDOwners map[string]protoreflect.FullName
+	conflicts         []string
+
+	// enumDetails adds x-enum-varnames and x-enum-descriptions to enum schemas and
+	// documents the numeric values accepted for string-encoded enums.
+	enumDetails bool
+
+	// protobufMediaType documents application/x-protobuf bodies next to JSON ones.
+	protobufMediaType bool
+
+	// bodyExamples attaches request and response examples assembled from field examples.
+	bodyExamples bool
+
+	// skipStreaming leaves streaming methods out of the document instead of describing
+	// them as event streams.
+	skipStreaming bool
+
+	// hoistInlineSchemas moves inline schemas that appear more than once into components.
+	hoistInlineSchemas bool
+
+	// channels are the streaming RPCs described by RenderAsyncAPI.
+	channels []eventChannel
+
+	// messages indexes the messages of registered files so response messages named in
+	// HttpConfig.responses can be resolved.
+	messages map[protoreflect.FullName]*protogen.Message
 }
 
 // NewGenerator creates a new OpenAPI generator with the specified output format.
@@ -49,8 +150,10 @@ func NewGenerator(format OutputFormat) *Generator {
 	addBuiltinErrorSchemas(schemas)
 
 	return &Generator{
-		format:  format,
-		schemas: schemas,
+		format:            format,
+		schemas:           schemas,
+		routeOwners:       make(map[string]protoreflect.FullName),
+		operationIDOwners: make(map[string]protoreflect.FullName),
 		doc: &v3.Document{
 			Version: "3.1.0",
 			Info: &base.Info{
@@ -77,6 +180,66 @@ func NewBundleGenerator(format OutputFormat) *Generator {
 	return g
 }
 
+// SetSchemaNaming overrides the schema naming strategy. It must be called before any
+// message or service is processed.
+func (g *Generator) SetSchemaNaming(naming SchemaNaming) {
+	g.schemaNaming = naming
+}
+
+// SetOperationIDNaming selects how operationIds are derived from RPCs. It must be called
+// before any service is processed.
+func (g *Generator) SetOperationIDNaming(naming OperationIDNaming) {
+	g.operationIDNaming = naming
+}
+
+// operationID returns the operationId of method under the configured naming.
+func (g *Generator) operationID(method *protogen.Method) string {
+	name := string(method.Desc.Name())
+	switch g.operationIDNaming {
+	case OperationIDNamingServiceMethod:
+		return string(method.Parent.Desc.Name()) + "_" + name
+	case OperationIDNamingLowerCamel:
+		return annotations.LowerFirst(name)
+	case OperationIDNamingFQN:
+		return string(method.Desc.FullName())
+	case OperationIDNamingMethod:
+	}
+	return name
+}
+
+// SetEnumDetails enables enum details: per-value descriptions from comments
+// (x-enum-descriptions), value names for integer enums (x-enum-varnames), and a oneOf
+// accepting either the name or the number for string-encoded enums without enum_value
+// mappings. It must be called before any message or service is processed.
+func (g *Generator) SetEnumDetails(enabled bool) {
+	g.enumDetails = enabled
+}
+
+// SetProtobufMediaType documents the binary protobuf encoding (application/x-protobuf)
+// next to JSON for request bodies and responses, matching the content negotiation of the
+// generated HTTP handlers. It must be called before any service is processed.
+func (g *Generator) SetProtobufMediaType(enabled bool) {
+	g.protobufMediaType = enabled
+}
+
+// addProtobufContent adds the application/x-protobuf media type to content when enabled.
+func (g *Generator) addProtobufContent(content *orderedmap.Map[string, *v3.MediaType]) {
+	if !g.protobufMediaType {
+		return
+	}
+	content.Set("application/x-protobuf", &v3.MediaType{
+		Schema: base.CreateSchemaProxy(&base.Schema{Type: []string{"string"}, Format: "binary"}),
+	})
+}
+
+// SetSkipStreaming leaves streaming methods (client- or server-streaming RPCs and methods
+// annotated with stream: true) out of the document. By default, methods annotated with
+// stream: true are documented as text/event-stream responses, and streaming RPCs without
+// it as the unary JSON exchange the generated HTTP handlers serve.
+func (g *Generator) SetSkipStreaming(skip bool) {
+	g.skipStreaming = skip
+}
+
 // SetInfo populates the OpenAPI info block. Empty strings are ignored so callers can
 // opt in to individual fields. Contact/license are set only when at least one of their
 // sub-fields is non-empty.
@@ -112,6 +275,25 @@ func (g *Generator) SetServers(urls []string) {
 	g.doc.Servers = servers
 }
 
+// RegisterFiles indexes the messages of files, including nested ones, so that messages
+// named in HttpConfig.responses can be documented. Call it with every file of the
+// protoc request (imports included) before processing services.
+func (g *Generator) RegisterFiles(files []*protogen.File) {
+	if g.messages == nil {
+		g.messages = make(map[protoreflect.FullName]*protogen.Message)
+	}
+	var register func(messages []*protogen.Message)
+	register = func(messages []*protogen.Message) {
+		for _, message := range messages {
+			g.messages[message.Desc.FullName()] = message
+			register(message.Messages)
+		}
+	}
+	for _, file := range files {
+		register(file.Messages)
+	}
+}
+
 // ProcessMessage processes a single message and adds it to the OpenAPI schemas.
 // This is now exported to be called from main.go.
 func (g *Generator) ProcessMessage(message *protogen.Message) {
@@ -140,6 +322,11 @@ func (g *Generator) ProcessService(service *protogen.Service) {
 	// In per-service mode we derive the title from the service name.
 	if !g.bundleMode {
 		g.doc.Info.Title = fmt.Sprintf("%s API", service.Desc.Name())
+
+		// A service-level host becomes the document's servers entry.
+		if host := annotations.GetServiceHost(service); host != "" {
+			g.SetServers([]string{host})
+		}
 	}
 
 	g.processService(service)
@@ -153,8 +340,17 @@ func (g *Generator) CollectReferencedMessages(service *protogen.Service) {
 
 	// Collect messages from all methods
 	for _, method := range service.Methods {
+		if g.skipStreaming && isStreamingMethod(method) {
+			continue
+		}
 		g.collectMessageRecursive(method.Input, processed)
 		g.collectMessageRecursive(method.Output, processed)
+
+		// Invalid responses are reported by annotations.GetMethodResponses callers.
+		responses, _ := annotations.GetMethodResponses(method)
+		for _, response := range responses {
+			g.collectMessageRecursive(g.messages[response.Message], processed)
+		}
 	}
 }
 
@@ -173,10 +369,15 @@ func (g *Generator) collectMessageRecursive(message *protogen.Message, processed
 
 	// Process this message
 	g.processMessage(message)
+	if _, ok := annotations.GetWellKnownType(message); ok {
+		return
+	}
 
 	// Process all field types
 	for _, field := range message.Fields {
-		if field.Message != nil {
+		// Well-known type fields are described inline by their JSON form, so they need
+		// no component schema.
+		if field.Message != nil && !annotations.IsWellKnownField(field) {
 			// Recursively process message fields
 			g.collectMessageRecursive(field.Message, processed)
 		}
@@ -185,7 +386,7 @@ func (g *Generator) collectMessageRecursive(message *protogen.Message, processed
 		if field.Desc.IsMap() && field.Message != nil {
 			// Map entry messages have a value field (field 2)
 			for _, mapField := range field.Message.Fields {
-				if mapField.Desc.Number() == 2 && mapField.Message != nil {
+				if mapField.Desc.Number() == 2 && mapField.Message != nil && !annotations.IsWellKnownField(mapField) {
 					g.collectMessageRecursive(mapField.Message, processed)
 				}
 			}
@@ -198,21 +399,70 @@ func (g *Generator) collectMessageRecursive(message *protogen.Message, processed
 	}
 }
 
-// getSchemaName generates a schema name for a protobuf message.
-// Since each service generates its own OpenAPI file, we can use simple message names
-// without package prefixes to avoid collisions.
+// getSchemaName generates a schema name for a protobuf message according to the
+// naming strategy. Per-service documents default to simple message names; bundles
+// default to package-prefixed names so schema slots stay unique across services.
 func (g *Generator) getSchemaName(message *protogen.Message) string {
-	if g.bundleMode {
-		// Proto-package-qualified name keeps schema slots unique across services.
+	fullName := string(message.Desc.FullName())
+
+	switch g.effectiveSchemaNaming() {
+	case SchemaNamingPackagePrefixed:
 		// e.g. sebuf.test.User -> sebuf_test_User. Built-in error schemas are added
 		// by name directly (they are not protogen.Messages) and are not affected.
-		return strings.ReplaceAll(string(message.Desc.FullName()), ".", "_")
+		return strings.ReplaceAll(fullName, ".", "_")
+	case SchemaNamingFQN:
+		return fullName
+	case SchemaNamingShortDedup:
+		return g.dedupSchemaName(message)
+	case SchemaNamingShort:
 	}
 	return string(message.Desc.Name())
 }
 
+// effectiveSchemaNaming returns the configured naming strategy or the mode's default.
+func (g *Generator) effectiveSchemaNaming() SchemaNaming {
+	if g.schemaNaming != "" {
+		return g.schemaNaming
+	}
+	if g.bundleMode {
+		return SchemaNamingPackagePrefixed
+	}
+	return SchemaNamingShort
+}
+
+// dedupSchemaName assigns the short name to the first message that asks for it and the
+// package-prefixed name to any later message with the same short name. Assignments are
+// cached so the schema key and every $ref agree.
+func (g *Generator) dedupSchemaName(message *protogen.Message) string {
+	fullName := message.Desc.FullName()
+	if name, ok := g.dedupNames[fullName]; ok {
+		return name
+	}
+	if g.dedupNames == nil {
+		g.dedupNames = make(map[protoreflect.FullName]string)
+		g.dedupOwners = make(map[string]protoreflect.FullName)
+		for _, builtin := range builtinSchemaNames {
+			g.dedupOwners[builtin] = ""
+		}
+	}
+
+	name := string(message.Desc.Name())
+	if _, taken := g.dedupOwners[name]; taken {
+		name = strings.ReplaceAll(string(fullName), ".", "_")
+	}
+	g.dedupNames[fullName] = name
+	g.dedupOwners[name] = fullName
+	return name
+}
+
 // processMessage converts a protobuf message to an OpenAPI schema.
 func (g *Generator) processMessage(message *protogen.Message) {
+	// A well-known type used as a request or response is described by its JSON form
+	if wkt, ok := annotations.GetWellKnownType(message); ok {
+		g.schemas.Set(g.getSchemaName(message), base.CreateSchemaProxy(wellKnownSchema(wkt)))
+		return
+	}
+
 	schema := g.buildObjectSchema(message)
 	schemaName := g.getSchemaName(message)
 	g.schemas.Set(schemaName, schema)
@@ -718,11 +968,28 @@ func (g *Generator) buildScalarAdditionalProperties(
 
 // processService converts a protobuf service to OpenAPI paths.
 func (g *Generator) processService(service *protogen.Service) {
+	g.addServiceTags(service)
 	for _, method := range service.Methods {
 		g.processMethod(service, method)
 	}
 }
 
+// addServiceTags lists the tags declared in the service's ServiceConfig.tags in the
+// document's top-level tags. A tag already listed, e.g. by another service in a bundle,
+// keeps its first declaration.
+func (g *Generator) addServiceTags(service *protogen.Service) {
+	for _, declared := range annotations.GetServiceTags(service) {
+		if slices.ContainsFunc(g.doc.Tags, func(tag *base.Tag) bool { return tag.Name == declared.Name }) {
+			continue
+		}
+		tag := &base.Tag{Name: declared.Name, Description: declared.Description}
+		if declared.ExternalDocsURL != "" {
+			tag.ExternalDocs = &base.ExternalDoc{URL: declared.ExternalDocsURL}
+		}
+		g.doc.Tags = append(g.doc.Tags, tag)
+	}
+}
+
 // methodHTTPInfo holds extracted HTTP configuration for a method.
 type methodHTTPInfo struct {
 	path       string
@@ -816,9 +1083,12 @@ func (g *Generator) buildResponses(method *protogen.Method) *orderedmap.Map[stri
 		Description: "Successful response",
 		Content:     orderedmap.New[string, *v3.MediaType](),
 	}
-	successResponse.Content.Set("application/json", &v3.MediaType{
+	successMedia := &v3.MediaType{
 		Schema: base.CreateSchemaProxyRef(outputSchemaRef),
-	})
+	}
+	g.addBodyExample(successMedia, method.Output)
+	successResponse.Content.Set("application/json", successMedia)
+	g.addProtobufContent(successResponse.Content)
 	responses.Set("200", successResponse)
 
 	// Validation error response
@@ -829,7 +1099,9 @@ func (g *Generator) buildResponses(method *protogen.Method) *orderedmap.Map[stri
 	validationErrorResponse.Content.Set("application/json", &v3.MediaType{
 		Schema: base.CreateSchemaProxyRef("#/components/schemas/ValidationError"),
 	})
+	g.addProtobufContent(validationErrorResponse.Content)
 	responses.Set("400", validationErrorResponse)
+	g.addMethodResponses(responses, method)
 
 	// Default error response - references the Error component schema
 	// which matches the sebuf.http.Error proto message (single "message" field)
@@ -840,11 +1112,64 @@ func (g *Generator) buildResponses(method *protogen.Method) *orderedmap.Map[stri
 	errorResponse.Content.Set("application/json", &v3.MediaType{
 		Schema: base.CreateSchemaProxyRef("#/components/schemas/Error"),
 	})
+	g.addProtobufContent(errorResponse.Content)
 	responses.Set("default", errorResponse)
 
 	return responses
 }
 
+// buildOperationExtensions converts the service and method openapi_extensions into
+// operation-level x-* fields. Invalid entries are reported by
+// annotations.GetOpenAPIExtensions callers and skipped here.
+func buildOperationExtensions(service *protogen.Service, method *protogen.Method) *orderedmap.Map[string, *yaml.Node] {
+	extensions, _ := annotations.GetOpenAPIExtensions(service, method)
+	if len(extensions) == 0 {
+		return nil
+	}
+
+	nodes := orderedmap.New[string, *yaml.Node]()
+	for _, extension := range extensions {
+		var doc yaml.Node
+		// JSON is valid YAML, so the value parses straight into a node.
+		if err := yaml.Unmarshal([]byte(extension.Value), &doc); err != nil || len(doc.Content) == 0 {
+			continue
+		}
+		value := doc.Content[0]
+		clearFlowStyle(value)
+		nodes.Set(extension.Name, value)
+	}
+	return nodes
+}
+
+// clearFlowStyle renders JSON objects and arrays parsed into node as block YAML,
+// matching the rest of the document.
+func clearFlowStyle(node *yaml.Node) {
+	node.Style &^= yaml.FlowStyle
+	for _, child := range node.Content {
+		clearFlowStyle(child)
+	}
+}
+
+// addMethodResponses adds the responses declared in HttpConfig.responses. An entry for
+// a status that is already documented (such as 400) replaces the default response.
+func (g *Generator) addMethodResponses(responses *orderedmap.Map[string, *v3.Response], method *protogen.Method) {
+	configured, _ := annotations.GetMethodResponses(method)
+	for _, config := range configured {
+		response := &v3.Response{Description: config.Description}
+		if response.Description == "" {
+			response.Description = stdhttp.StatusText(config.Status)
+		}
+		if message := g.messages[config.Message]; message != nil {
+			response.Content = orderedmap.New[string, *v3.MediaType]()
+			response.Content.Set("application/json", &v3.MediaType{
+				Schema: base.CreateSchemaProxyRef("#/components/schemas/" + g.getSchemaName(message)),
+			})
+			g.addProtobufContent(response.Content)
+		}
+		responses.Set(strconv.Itoa(config.Status), response)
+	}
+}
+
 // assignOperationToPathItem assigns an operation to the correct HTTP method on a path item.
 func assignOperationToPathItem(pathItem *v3.PathItem, httpMethod string, operation *v3.Operation) {
 	switch httpMethod {
@@ -863,24 +1188,147 @@ func assignOperationToPathItem(pathItem *v3.PathItem, httpMethod string, operati
 	}
 }
 
-// processMethod converts a protobuf RPC method to an OpenAPI operation.
-func (g *Generator) processMethod(service *protogen.Service, method *protogen.Method) {
-	info := extractMethodHTTPInfo(service, method)
+// Conflicts describes operations that could not be merged into the document: two RPCs
+// mapped to the same HTTP method and path (the later one replaces the earlier), or
+// sharing an operationId. It is most relevant in bundle mode, where services from
+// different files and packages share one document.
+func (g *Generator) Conflicts() []string {
+	return g.conflicts
+}
 
-	// Check if this is an SSE streaming method
-	methodConfig := annotations.GetMethodHTTPConfig(method)
-	isSSE := methodConfig != nil && methodConfig.Stream
+// recordOperation registers the route and operationId of method, noting any conflict
+// with an RPC processed earlier.
+func (g *Generator) recordOperation(method *protogen.Method, info methodHTTPInfo, operationID string) {
+	owner := method.Desc.FullName()
+
+	route := strings.ToUpper(info.httpMethod) + " " + info.path
+	if previous, taken := g.routeOwners[route]; taken {
+		g.conflicts = append(g.conflicts, fmt.Sprintf("%s: route %s is already used by %s", owner, route, previous))
+	} else {
+		g.routeOwners[route] = owner
+	}
 
+	g.recordOperationID(owner, operationID)
+}
+
+// recordOperationID registers an operationId, noting a conflict if an RPC processed
+// earlier already uses it.
+func (g *Generator) recordOperationID(owner protoreflect.FullName, operationID string) {
+	if previous, taken := g.operationIDOwners[operationID]; taken {
+		g.conflicts = append(g.conflicts,
+			fmt.Sprintf("%s: operationId %q is already used by %s", owner, operationID, previous))
+	} else {
+		g.operationIDOwners[operationID] = owner
+	}
+}
+
+// isStreamingMethod reports whether method streams in either direction, through the
+// protobuf stream keyword or the stream HttpConfig option.
+func isStreamingMethod(method *protogen.Method) bool {
+	if method.Desc.IsStreamingClient() || method.Desc.IsStreamingServer() {
+		return true
+	}
+	config := annotations.GetMethodHTTPConfig(method)
+	return config != nil && config.Stream
+}
+
+// newOperation creates the operation for method with the fields shared by endpoints and
+// webhooks: operationId, summary, description, tags, deprecation and vendor extensions.
+func (g *Generator) newOperation(service *protogen.Service, method *protogen.Method) *v3.Operation {
 	operation := &v3.Operation{
-		OperationId: string(method.Desc.Name()),
+		OperationId: g.operationID(method),
 		Summary:     string(method.Desc.Name()),
-		Tags:        []string{string(service.Desc.Name())},
+		Tags:        annotations.GetOperationTags(service, method),
 	}
 
 	if method.Comments.Leading != "" {
 		operation.Description = strings.TrimSpace(string(method.Comments.Leading))
 	}
 
+	if methodOpts, ok := method.Desc.Options().(*descriptorpb.MethodOptions); ok && methodOpts.GetDeprecated() {
+		operation.Deprecated = proto.Bool(true)
+	}
+	operation.Extensions = buildOperationExtensions(service, method)
+	return operation
+}
+
+// buildRequestBody creates a required JSON request body referencing the input schema.
+func (g *Generator) buildRequestBody(method *protogen.Method) *v3.RequestBody {
+	inputSchemaRef := fmt.Sprintf("#/components/schemas/%s", g.getSchemaName(method.Input))
+	requestBody := &v3.RequestBody{
+		Required: proto.Bool(true),
+		Content:  orderedmap.New[string, *v3.MediaType](),
+	}
+	mediaType := &v3.MediaType{
+		Schema: base.CreateSchemaProxyRef(inputSchemaRef),
+	}
+	g.addBodyExample(mediaType, method.Input)
+	requestBody.Content.Set("application/json", mediaType)
+	return requestBody
+}
+
+// processWebhook documents a method annotated with webhook: true under the document's
+// webhooks, keyed by the RPC name. The server sends the input message to subscribers,
+// which acknowledge it with the output message.
+func (g *Generator) processWebhook(service *protogen.Service, method *protogen.Method) {
+	name := string(method.Desc.Name())
+	operation := g.newOperation(service, method)
+	operation.RequestBody = g.buildRequestBody(method)
+	g.addProtobufContent(operation.RequestBody.Content)
+
+	outputSchemaRef := fmt.Sprintf("#/components/schemas/%s", g.getSchemaName(method.Output))
+	acknowledgement := &v3.Response{
+		Description: "Webhook received",
+		Content:     orderedmap.New[string, *v3.MediaType](),
+	}
+	acknowledgement.Content.Set("application/json", &v3.MediaType{
+		Schema: base.CreateSchemaProxyRef(outputSchemaRef),
+	})
+	g.addProtobufContent(acknowledgement.Content)
+	responses := orderedmap.New[string, *v3.Response]()
+	responses.Set("200", acknowledgement)
+	operation.Responses = &v3.Responses{Codes: responses}
+
+	g.recordOperationID(method.Desc.FullName(), operation.OperationId)
+
+	if g.doc.Webhooks == nil {
+		g.doc.Webhooks = orderedmap.New[string, *v3.PathItem]()
+	}
+	pathItem, exists := g.doc.Webhooks.Get(name)
+	if !exists {
+		pathItem = &v3.PathItem{}
+	}
+	assignOperationToPathItem(pathItem, extractMethodHTTPInfo(service, method).httpMethod, operation)
+	g.doc.Webhooks.Set(name, pathItem)
+}
+
+// processMethod converts a protobuf RPC method to an OpenAPI operation.
+func (g *Generator) processMethod(service *protogen.Service, method *protogen.Method) {
+	if g.skipStreaming && isStreamingMethod(method) {
+		return
+	}
+	methodConfig := annotations.GetMethodHTTPConfig(method)
+	if methodConfig != nil && methodConfig.Webhook {
+		g.processWebhook(service, method)
+		return
+	}
+	info := extractMethodHTTPInfo(service, method)
+
+	// Methods annotated with stream: true respond with an event stream of output messages.
+	// The generated handlers serve any other RPC, even one declared with the protobuf
+	// stream keyword, as a single JSON exchange, so it is documented as one.
+	isSSE := methodConfig != nil && methodConfig.Stream
+
+	operation := g.newOperation(service, method)
+
+	// In bundle mode the document-level servers span every service, so a service-level
+	// host is attached to each of its operations instead.
+	if g.bundleMode {
+		if host := annotations.GetServiceHost(service); host != "" {
+			operation.Servers = []*v3.Server{{URL: host}}
+		}
+	}
+
 	// Build parameters
 	var parameters []*v3.Parameter
 	allHeaders := annotations.CombineHeaders(
@@ -899,22 +1347,19 @@ func (g *Generator) processMethod(service *protogen.Service, method *protogen.Me
 
 	// Add request body for POST, PUT, PATCH
 	if info.httpMethod == httpMethodPost || info.httpMethod == httpMethodPut || info.httpMethod == httpMethodPatch {
-		inputSchemaRef := fmt.Sprintf("#/components/schemas/%s", g.getSchemaName(method.Input))
-		operation.RequestBody = &v3.RequestBody{
-			Required: proto.Bool(true),
-			Content:  orderedmap.New[string, *v3.MediaType](),
-		}
-		operation.RequestBody.Content.Set("application/json", &v3.MediaType{
-			Schema: base.CreateSchemaProxyRef(inputSchemaRef),
-		})
+		operation.RequestBody = g.buildRequestBody(method)
+		g.addProtobufContent(operation.RequestBody.Content)
 	}
 
 	if isSSE {
 		operation.Responses = &v3.Responses{Codes: g.buildSSEResponses(method)}
+		g.recordEventChannel(operation.OperationId, info.path, g.getSchemaName(method.Output), operation.Description)
 	} else {
 		operation.Responses = &v3.Responses{Codes: g.buildResponses(method)}
 	}
 
+	g.recordOperation(method, info, operation.OperationId)
+
 	// Add to path items
 	existingPathItem, exists := g.doc.Paths.PathItems.Get(info.path)
 	if !exists {
@@ -962,7 +1407,9 @@ func (g *Generator) buildSSEResponses(method *protogen.Method) *orderedmap.Map[s
 	validationErrorResponse.Content.Set("application/json", &v3.MediaType{
 		Schema: base.CreateSchemaProxyRef("#/components/schemas/ValidationError"),
 	})
+	g.addProtobufContent(validationErrorResponse.Content)
 	responses.Set("400", validationErrorResponse)
+	g.addMethodResponses(responses, method)
 
 	// Default error response
 	errorResponse := &v3.Response{
@@ -972,6 +1419,7 @@ func (g *Generator) buildSSEResponses(method *protogen.Method) *orderedmap.Map[s
 	errorResponse.Content.Set("application/json", &v3.MediaType{
 		Schema: base.CreateSchemaProxyRef("#/components/schemas/Error"),
 	})
+	g.addProtobufContent(errorResponse.Content)
 	responses.Set("default", errorResponse)
 
 	return responses
@@ -1094,7 +1542,17 @@ func addBuiltinErrorSchemas(schemas *orderedmap.Map[string, *base.SchemaProxy])
 
 // Render outputs the OpenAPI document in the specified format.
 func (g *Generator) Render() ([]byte, error) {
-	switch g.format {
+	return g.RenderAs(g.format)
+}
+
+// RenderAs renders the OpenAPI document in the given format, regardless of the format
+// the generator was created with. It lets one processed document be written as both
+// YAML and JSON.
+func (g *Generator) RenderAs(format OutputFormat) ([]byte, error) {
+	if g.hoistInlineSchemas {
+		return g.renderHoisted(format)
+	}
+	switch format {
 	case FormatJSON:
 		// First marshal to YAML (which works correctly with libopenapi)
 		yamlData, err := yaml.Marshal(g.doc)
@@ -0,0 +1,198 @@
+package openapiv3
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	yaml "go.yaml.in/yaml/v4"
+	k8syaml "sigs.k8s.io/yaml"
+)
+
+// minHoistOccurrences is how many times an inline schema must appear to be hoisted.
+const minHoistOccurrences = 2
+
+// SetHoistInlineSchemas enables hoisting inline schemas that appear more than once into
+// components.schemas. It applies to RenderAs and RenderSplit.
+func (g *Generator) SetHoistInlineSchemas(enabled bool) {
+	g.hoistInlineSchemas = enabled
+}
+
+// renderHoisted renders the document like RenderAs with repeated inline schemas hoisted.
+func (g *Generator) renderHoisted(format OutputFormat) ([]byte, error) {
+	data, err := yaml.Marshal(g.doc)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal to YAML: %w", err)
+	}
+	var root yaml.Node
+	if unmarshalErr := yaml.Unmarshal(data, &root); unmarshalErr != nil {
+		return nil, fmt.Errorf("failed to parse rendered document: %w", unmarshalErr)
+	}
+	if len(root.Content) == 0 {
+		return data, nil
+	}
+	hoistInlineSchemas(root.Content[0])
+
+	data, err = yaml.Marshal(&root)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal to YAML: %w", err)
+	}
+	if format != FormatJSON {
+		return data, nil
+	}
+	jsonData, err := k8syaml.YAMLToJSON(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert YAML to JSON: %w", err)
+	}
+	return jsonData, nil
+}
+
+// schemaVisitor is called for every schema in a schema position (a property, array
+// items, map values, a oneOf/anyOf/allOf member or a media type / parameter schema).
+// The schema is parent.Content[index]; hint is a name derived from where it sits. The
+// schema is descended into only when the visitor returns true.
+type schemaVisitor func(parent *yaml.Node, index int, hint string) bool
+
+// hoistInlineSchemas moves every inline enum or object schema that appears at least twice
+// in doc into components.schemas and replaces each occurrence with a $ref. A hoisted
+// schema is named after the property it was first found on (e.g. "status" becomes
+// "Status"), with a numeric suffix when the name is taken. Component schemas themselves
+// and plain scalar or $ref schemas are never hoisted.
+func hoistInlineSchemas(doc *yaml.Node) {
+	counts := make(map[string]int)
+	walkSchemaPositions(doc, "Inline", func(parent *yaml.Node, index int, _ string) bool {
+		if key, ok := hoistKey(parent.Content[index]); ok {
+			counts[key]++
+		}
+		return true
+	})
+
+	componentsIndex := mappingIndex(doc, "components")
+	if componentsIndex < 0 {
+		return
+	}
+	components := doc.Content[componentsIndex+1]
+	schemasIndex := mappingIndex(components, "schemas")
+	if schemasIndex < 0 {
+		return
+	}
+	schemas := components.Content[schemasIndex+1]
+	taken := make(map[string]bool)
+	for i := 0; i+1 < len(schemas.Content); i += mappingPair {
+		taken[schemas.Content[i].Value] = true
+	}
+
+	names := make(map[string]string)
+	var hoisted []namedSchema
+	walkSchemaPositions(doc, "Inline", func(parent *yaml.Node, index int, hint string) bool {
+		schema := parent.Content[index]
+		key, ok := hoistKey(schema)
+		if !ok || counts[key] < minHoistOccurrences {
+			return true
+		}
+		name, seen := names[key]
+		if !seen {
+			name = freeSchemaName(hint, taken)
+			taken[name] = true
+			names[key] = name
+			hoisted = append(hoisted, namedSchema{name: name, node: schema})
+		}
+		parent.Content[index] = schemaRefNode(name)
+		return false
+	})
+
+	for _, schema := range hoisted {
+		schemas.Content = append(schemas.Content, &yaml.Node{Kind: yaml.ScalarNode, Value: schema.name}, schema.node)
+	}
+}
+
+// hoistKey returns the canonical text of an inline enum or object schema, used to find
+// identical schemas. Other schemas are not candidates for hoisting.
+func hoistKey(schema *yaml.Node) (string, bool) {
+	if schema.Kind != yaml.MappingNode || mappingIndex(schema, "$ref") >= 0 {
+		return "", false
+	}
+	if mappingIndex(schema, "enum") < 0 && mappingIndex(schema, "properties") < 0 {
+		return "", false
+	}
+	data, err := yaml.Marshal(schema)
+	if err != nil {
+		return "", false
+	}
+	return string(data), true
+}
+
+// freeSchemaName returns hint, or hint with the lowest numeric suffix from 2 on, that is
+// not taken.
+func freeSchemaName(hint string, taken map[string]bool) string {
+	if !taken[hint] {
+		return hint
+	}
+	for suffix := 2; ; suffix++ {
+		if name := hint + strconv.Itoa(suffix); !taken[name] {
+			return name
+		}
+	}
+}
+
+// schemaRefNode returns a {$ref: "#/components/schemas/{name}"} schema.
+func schemaRefNode(name string) *yaml.Node {
+	return &yaml.Node{Kind: yaml.MappingNode, Tag: "!!map", Content: []*yaml.Node{
+		{Kind: yaml.ScalarNode, Value: "$ref"},
+		{Kind: yaml.ScalarNode, Value: schemaRefPrefix + name},
+	}}
+}
+
+// walkSchemaPositions calls visit for every schema in a schema position under node, in
+// document order.
+func walkSchemaPositions(node *yaml.Node, hint string, visit schemaVisitor) {
+	switch node.Kind {
+	case yaml.MappingNode:
+		for i := 0; i+1 < len(node.Content); i += mappingPair {
+			key, value := node.Content[i].Value, node.Content[i+1]
+			switch key {
+			case "properties":
+				for j := 0; j+1 < len(value.Content); j += mappingPair {
+					visitSchema(value, j+1, upperFirst(value.Content[j].Value), visit)
+				}
+			case "items", "additionalProperties", "schema":
+				visitSchema(node, i+1, hint, visit)
+			case "oneOf", "anyOf", "allOf":
+				for j := range value.Content {
+					visitSchema(value, j, hint, visit)
+				}
+			case "schemas":
+				for j := 0; j+1 < len(value.Content); j += mappingPair {
+					walkSchemaPositions(value.Content[j+1], value.Content[j].Value, visit)
+				}
+			case "example", "examples", "default", "enum", "const":
+				// Literal values, not schemas.
+			default:
+				if !strings.HasPrefix(key, "x-") {
+					walkSchemaPositions(value, hint, visit)
+				}
+			}
+		}
+	case yaml.SequenceNode, yaml.DocumentNode:
+		for _, child := range node.Content {
+			walkSchemaPositions(child, hint, visit)
+		}
+	case yaml.ScalarNode, yaml.AliasNode:
+	}
+}
+
+// visitSchema visits the schema at parent.Content[index] and, if visit asks for it, the
+// schemas nested in it.
+func visitSchema(parent *yaml.Node, index int, hint string, visit schemaVisitor) {
+	if visit(parent, index, hint) {
+		walkSchemaPositions(parent.Content[index], hint, visit)
+	}
+}
+
+// upperFirst upper-cases the first letter of a property name to form a schema name.
+func upperFirst(s string) string {
+	if s == "" {
+		return s
+	}
+	return strings.ToUpper(s[:1]) + s[1:]
+}
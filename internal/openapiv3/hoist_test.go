@@ -0,0 +1,68 @@
+package openapiv3_test
+
+import (
+	"testing"
+
+	yaml "go.yaml.in/yaml/v4"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+func TestHoistInlineSchemas(t *testing.T) {
+	file := statusEnumFile()
+	pastStatuses := descField("past_statuses", 3, descriptorpb.FieldDescriptorProto_TYPE_ENUM)
+	pastStatuses.TypeName = proto.String("." + descTestPkg + ".Status")
+	pastStatuses.Label = descriptorpb.FieldDescriptorProto_LABEL_REPEATED.Enum()
+	file.MessageType[1].Field = append(file.MessageType[1].Field, pastStatuses)
+	service := findDescService(t, buildDescPlugin(t, file), "UserService")
+
+	type schema struct {
+		Ref   string   `yaml:"$ref"`
+		Enum  []string `yaml:"enum"`
+		Items *schema  `yaml:"items"`
+	}
+	for _, enabled := range []bool{false, true} {
+		gen := openapiv3.NewGenerator(openapiv3.FormatYAML)
+		gen.SetHoistInlineSchemas(enabled)
+		gen.CollectReferencedMessages(service)
+		gen.ProcessService(service)
+
+		data, err := gen.RenderAs(openapiv3.FormatYAML)
+		if err != nil {
+			t.Fatalf("RenderAs() error = %v", err)
+		}
+		var doc struct {
+			Components struct {
+				Schemas map[string]struct {
+					schema     `yaml:",inline"`
+					Properties map[string]schema `yaml:"properties"`
+				} `yaml:"schemas"`
+			} `yaml:"components"`
+		}
+		if unmarshalErr := yaml.Unmarshal(data, &doc); unmarshalErr != nil {
+			t.Fatalf("parsing output: %v", unmarshalErr)
+		}
+
+		user := doc.Components.Schemas["User"].Properties
+		status, hoisted := doc.Components.Schemas["Status"]
+		if !enabled {
+			if hoisted || len(user["status"].Enum) == 0 || user["pastStatuses"].Items == nil ||
+				len(user["pastStatuses"].Items.Enum) == 0 {
+				t.Errorf("without hoisting the enum should stay inline: %s", data)
+			}
+			continue
+		}
+		if !hoisted || len(status.Enum) != 2 {
+			t.Fatalf("the repeated enum should be hoisted to components.schemas.Status: %s", data)
+		}
+		const ref = "#/components/schemas/Status"
+		if user["status"].Ref != ref || user["pastStatuses"].Items == nil || user["pastStatuses"].Items.Ref != ref {
+			t.Errorf("both enum schemas should reference %s: %s", ref, data)
+		}
+		if problems, checkErr := openapiv3.CheckDocument(data); checkErr != nil || len(problems) > 0 {
+			t.Errorf("CheckDocument() = %v, %v; want no problems", problems, checkErr)
+		}
+	}
+}
@@ -1,6 +1,7 @@
 package openapiv3_test
 
 import (
+	"bytes"
 	"os"
 	"os/exec"
 	"path/filepath"
@@ -141,7 +142,58 @@ func TestPluginErrorHandling(t *testing.T) {
 				"--proto_path=testdata/proto",
 				"testdata/proto/simple_service.proto",
 			},
-			expectError: false, // Should default to YAML and not error
+			expectError: true,
+			errorMsg:    `invalid format "invalid"`,
+		},
+		{
+			name: "unknown_parameter_without_value",
+			args: []string{
+				"--plugin=protoc-gen-openapiv3=" + pluginPath,
+				"--openapiv3_out=" + t.TempDir(),
+				"--openapiv3_opt=bogus",
+				"--proto_path=testdata/proto",
+				"testdata/proto/simple_service.proto",
+			},
+			expectError: true,
+			errorMsg:    "unknown parameter(s) bogus",
+		},
+		{
+			name:        "bundle_route_conflict",
+			args:        bundleConflictArgs(pluginPath, t.TempDir()),
+			expectError: true,
+			errorMsg: "conflict.admin.AdminDirectoryService.LookupUser: route GET /users/{id} is already used by " +
+				"conflict.users.DirectoryService.GetUser",
+		},
+		{
+			name:        "bundle_operation_id_conflict",
+			args:        bundleConflictArgs(pluginPath, t.TempDir()),
+			expectError: true,
+			errorMsg: `conflict.admin.AdminDirectoryService.GetUser: operationId "GetUser" is already used by ` +
+				"conflict.users.DirectoryService.GetUser",
+		},
+		{
+			name: "invalid_extension_name",
+			args: []string{
+				"--plugin=protoc-gen-openapiv3=" + pluginPath,
+				"--openapiv3_out=" + t.TempDir(),
+				"--proto_path=testdata/proto",
+				"--proto_path=../../proto",
+				"testdata/proto/invalid_extension_name.proto",
+			},
+			expectError: true,
+			errorMsg:    `OpenAPI extension name "owner" must start with "x-"`,
+		},
+		{
+			name: "invalid_extension_value",
+			args: []string{
+				"--plugin=protoc-gen-openapiv3=" + pluginPath,
+				"--openapiv3_out=" + t.TempDir(),
+				"--proto_path=testdata/proto",
+				"--proto_path=../../proto",
+				"testdata/proto/invalid_extension_value.proto",
+			},
+			expectError: true,
+			errorMsg:    "OpenAPI extension x-owner has a value that is not valid JSON: widgets-team",
 		},
 	}
 
@@ -165,6 +217,19 @@ func TestPluginErrorHandling(t *testing.T) {
 	}
 }
 
+// bundleConflictArgs bundles two files whose RPCs collide on a route and an operationId.
+func bundleConflictArgs(pluginPath, outDir string) []string {
+	return []string{
+		"--plugin=protoc-gen-openapiv3=" + pluginPath,
+		"--openapiv3_out=" + outDir,
+		"--openapiv3_opt=bundle=true,bundle_only=true",
+		"--proto_path=testdata/proto",
+		"--proto_path=../../proto",
+		"testdata/proto/bundle_conflict_users.proto",
+		"testdata/proto/bundle_conflict_admin.proto",
+	}
+}
+
 // TestPluginFormatOptions tests various format options.
 func TestPluginFormatOptions(t *testing.T) {
 	// Build the plugin binary for testing
@@ -356,6 +421,66 @@ func TestPluginServiceGeneration(t *testing.T) {
 	}
 }
 
+// TestPluginOutputIsDeterministic runs protoc repeatedly on the same files and requires
+// byte-identical documents, so output ordered by Go map iteration fails it.
+func TestPluginOutputIsDeterministic(t *testing.T) {
+	// Build the plugin binary for testing
+	pluginPath := "./protoc-gen-openapiv3-determinism-test"
+	buildCmd := exec.Command("go", "build", "-o", pluginPath, "../../cmd/protoc-gen-openapiv3")
+	if err := buildCmd.Run(); err != nil {
+		t.Fatalf("Failed to build plugin: %v", err)
+	}
+	defer os.Remove(pluginPath)
+
+	generate := func(t *testing.T, format string) map[string][]byte {
+		t.Helper()
+		tempDir := t.TempDir()
+		cmd := exec.Command("protoc",
+			"--plugin=protoc-gen-openapiv3="+pluginPath,
+			"--openapiv3_out="+tempDir,
+			"--openapiv3_opt=format="+format,
+			"--proto_path=testdata/proto",
+			"--proto_path=../../proto",
+			"testdata/proto/multiple_services.proto",
+			"testdata/proto/operation_tags.proto",
+		)
+		if output, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("protoc failed: %v\nOutput: %s", err, string(output))
+		}
+
+		files, listErr := os.ReadDir(tempDir)
+		if listErr != nil {
+			t.Fatalf("Failed to list output directory: %v", listErr)
+		}
+		outputs := make(map[string][]byte, len(files))
+		for _, file := range files {
+			content, readErr := os.ReadFile(filepath.Join(tempDir, file.Name()))
+			if readErr != nil {
+				t.Fatalf("Failed to read %s: %v", file.Name(), readErr)
+			}
+			outputs[file.Name()] = content
+		}
+		return outputs
+	}
+
+	for _, format := range []string{"yaml", "json"} {
+		t.Run(format, func(t *testing.T) {
+			want := generate(t, format)
+			for range 5 {
+				got := generate(t, format)
+				if len(got) != len(want) {
+					t.Fatalf("protoc generated %d files, then %d", len(want), len(got))
+				}
+				for name, content := range want {
+					if !bytes.Equal(got[name], content) {
+						t.Fatalf("%s changed between protoc runs:\n%s\n---\n%s", name, content, got[name])
+					}
+				}
+			}
+		})
+	}
+}
+
 // Helper functions
 
 func getFileNames(files []os.DirEntry) []string {
@@ -0,0 +1,114 @@
+package openapiv3
+
+import (
+	"strings"
+
+	yaml "go.yaml.in/yaml/v4"
+	"google.golang.org/protobuf/compiler/protogen"
+)
+
+// jsonSchemaDialect is the JSON Schema draft RenderJSONSchema targets, the one OpenAPI
+// 3.1 schemas are written in.
+const jsonSchemaDialect = "https://json-schema.org/draft/2020-12/schema"
+
+// defsRefPrefix is the local reference prefix of $defs in a standalone JSON Schema.
+const defsRefPrefix = "#/$defs/"
+
+// RenderJSONSchema renders a standalone JSON Schema (draft 2020-12) document for message,
+// for consumers that do not read OpenAPI. The message and every message it references
+// become $defs, built exactly as the OpenAPI component schemas are, and the root schema
+// refers to the message's definition. Schemas the message does not reach, such as the
+// built-in error schemas, are left out.
+func (g *Generator) RenderJSONSchema(message *protogen.Message, format OutputFormat) ([]byte, error) {
+	g.collectMessageRecursive(message, make(map[string]bool))
+	schemas, err := g.schemasNode()
+	if err != nil {
+		return nil, err
+	}
+
+	name := g.getSchemaName(message)
+	defs := reachableSchemas(schemas, name)
+	rewriteRefsToDefs(defs)
+
+	root := &yaml.Node{Kind: yaml.MappingNode, Tag: "!!map", Content: []*yaml.Node{
+		{Kind: yaml.ScalarNode, Value: "$schema"},
+		{Kind: yaml.ScalarNode, Value: jsonSchemaDialect},
+		{Kind: yaml.ScalarNode, Value: "title"},
+		{Kind: yaml.ScalarNode, Value: string(message.Desc.FullName())},
+		{Kind: yaml.ScalarNode, Value: "$ref"},
+		{Kind: yaml.ScalarNode, Value: defsRefPrefix + name},
+		{Kind: yaml.ScalarNode, Value: "$defs"},
+		defs,
+	}}
+	return encodeNode(root, format)
+}
+
+// reachableSchemas returns the entries of the schemas mapping that the schema called root
+// reaches through component $refs, root included, in their original order.
+func reachableSchemas(schemas *yaml.Node, root string) *yaml.Node {
+	byName := make(map[string]*yaml.Node)
+	for i := 0; i+1 < len(schemas.Content); i += mappingPair {
+		byName[schemas.Content[i].Value] = schemas.Content[i+1]
+	}
+
+	reached := make(map[string]bool)
+	var visit func(name string)
+	visit = func(name string) {
+		schema, ok := byName[name]
+		if !ok || reached[name] {
+			return
+		}
+		reached[name] = true
+		for _, ref := range schemaRefNames(schema) {
+			visit(ref)
+		}
+	}
+	visit(root)
+
+	defs := &yaml.Node{Kind: yaml.MappingNode, Tag: "!!map"}
+	for i := 0; i+1 < len(schemas.Content); i += mappingPair {
+		if reached[schemas.Content[i].Value] {
+			defs.Content = append(defs.Content, schemas.Content[i], schemas.Content[i+1])
+		}
+	}
+	return defs
+}
+
+// schemaRefNames returns the names of the component schemas referenced under node.
+func schemaRefNames(node *yaml.Node) []string {
+	var names []string
+	if node.Kind == yaml.MappingNode {
+		for i := 0; i+1 < len(node.Content); i += mappingPair {
+			key, value := node.Content[i], node.Content[i+1]
+			if key.Value != "$ref" || value.Kind != yaml.ScalarNode {
+				continue
+			}
+			if name, ok := strings.CutPrefix(value.Value, schemaRefPrefix); ok && name != "" {
+				names = append(names, name)
+			}
+		}
+	}
+	for _, child := range node.Content {
+		names = append(names, schemaRefNames(child)...)
+	}
+	return names
+}
+
+// rewriteRefsToDefs points every component schema $ref under node at "#/$defs/{Name}".
+func rewriteRefsToDefs(node *yaml.Node) {
+	if node.Kind == yaml.MappingNode {
+		for i := 0; i+1 < len(node.Content); i += mappingPair {
+			key, value := node.Content[i], node.Content[i+1]
+			if key.Value != "$ref" || value.Kind != yaml.ScalarNode {
+				continue
+			}
+			if name, ok := strings.CutPrefix(value.Value, schemaRefPrefix); ok && name != "" {
+				value.Value = defsRefPrefix + name
+				value.Style = 0
+			}
+		}
+	}
+	for _, child := range node.Content {
+		rewriteRefsToDefs(child)
+	}
+}
@@ -0,0 +1,65 @@
+package openapiv3_test
+
+import (
+	"encoding/json"
+	"testing"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+func TestRenderJSONSchema(t *testing.T) {
+	file := descFile("profile.proto", descTestPkg,
+		[]*descriptorpb.DescriptorProto{
+			descMessage("Address", descStringField("city", 1)),
+			descMessage("Profile",
+				descStringField("name", 1),
+				descMessageField("home", 2, descTestPkg+".Address"),
+			),
+			descMessage("Unrelated", descStringField("id", 1)),
+		},
+	)
+	plugin := buildDescPlugin(t, file)
+	var profile *protogen.Message
+	for _, message := range plugin.Files[0].Messages {
+		if message.Desc.Name() == "Profile" {
+			profile = message
+		}
+	}
+
+	data, err := openapiv3.NewGenerator(openapiv3.FormatJSON).RenderJSONSchema(profile, openapiv3.FormatJSON)
+	if err != nil {
+		t.Fatalf("RenderJSONSchema() error = %v", err)
+	}
+	var doc struct {
+		Schema string `json:"$schema"`
+		Title  string `json:"title"`
+		Ref    string `json:"$ref"`
+		Defs   map[string]struct {
+			Properties map[string]struct {
+				Ref string `json:"$ref"`
+			} `json:"properties"`
+		} `json:"$defs"`
+	}
+	if unmarshalErr := json.Unmarshal(data, &doc); unmarshalErr != nil {
+		t.Fatalf("parsing output: %v\n%s", unmarshalErr, data)
+	}
+
+	if doc.Schema != "https://json-schema.org/draft/2020-12/schema" {
+		t.Errorf("$schema = %q, want draft 2020-12", doc.Schema)
+	}
+	if doc.Title != descTestPkg+".Profile" || doc.Ref != "#/$defs/Profile" {
+		t.Errorf("title, $ref = %q, %q, want %s.Profile, #/$defs/Profile", doc.Title, doc.Ref, descTestPkg)
+	}
+	if len(doc.Defs) != 2 {
+		t.Errorf("$defs = %v, want only Profile and Address", doc.Defs)
+	}
+	if ref := doc.Defs["Profile"].Properties["home"].Ref; ref != "#/$defs/Address" {
+		t.Errorf("Profile.home $ref = %q, want #/$defs/Address", ref)
+	}
+	if _, ok := doc.Defs["Address"]; !ok {
+		t.Error("$defs has no Address schema")
+	}
+}
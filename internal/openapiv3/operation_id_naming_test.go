@@ -0,0 +1,53 @@
+package openapiv3_test
+
+import (
+	"testing"
+
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+func TestOperationIDNaming(t *testing.T) {
+	tests := []struct {
+		naming openapiv3.OperationIDNaming
+		want   string
+	}{
+		{"", "GetUser"},
+		{openapiv3.OperationIDNamingMethod, "GetUser"},
+		{openapiv3.OperationIDNamingServiceMethod, "UserService_GetUser"},
+		{openapiv3.OperationIDNamingLowerCamel, "getUser"},
+		{openapiv3.OperationIDNamingFQN, descTestPkg + ".UserService.GetUser"},
+	}
+
+	plugin := buildDescPlugin(t, userServiceFile(nil))
+	service := findDescService(t, plugin, "UserService")
+	for _, tt := range tests {
+		t.Run(string(tt.naming), func(t *testing.T) {
+			gen := openapiv3.NewGenerator(openapiv3.FormatYAML)
+			gen.SetOperationIDNaming(tt.naming)
+			gen.CollectReferencedMessages(service)
+			gen.ProcessService(service)
+
+			pathItem, ok := gen.Doc().Paths.PathItems.Get("/users/{id}")
+			if !ok || pathItem.Get == nil {
+				t.Fatal("expected GET /users/{id} operation")
+			}
+			if got := pathItem.Get.OperationId; got != tt.want {
+				t.Errorf("operationId = %q, want %q", got, tt.want)
+			}
+			if got := pathItem.Get.Summary; got != "GetUser" {
+				t.Errorf("summary = %q, want the RPC name GetUser", got)
+			}
+		})
+	}
+}
+
+func TestParseOperationIDNaming(t *testing.T) {
+	for _, valid := range []string{"method", "service_method", "lower_camel", "fqn"} {
+		if _, err := openapiv3.ParseOperationIDNaming(valid); err != nil {
+			t.Errorf("ParseOperationIDNaming(%q) returned error: %v", valid, err)
+		}
+	}
+	if _, err := openapiv3.ParseOperationIDNaming("snake"); err == nil {
+		t.Error("ParseOperationIDNaming(\"snake\") should fail")
+	}
+}
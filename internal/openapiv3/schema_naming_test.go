@@ -0,0 +1,126 @@
+package openapiv3_test
+
+import (
+	"testing"
+
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+// collidingUserFiles builds two packages that both define User (and Error), plus a
+// service in the first package whose response references both.
+func collidingUserFiles() []*descriptorpb.FileDescriptorProto {
+	const otherPkg = "test.other.v1"
+	other := descFile("other.proto", otherPkg, []*descriptorpb.DescriptorProto{
+		descMessage("User", descStringField("email", 1)),
+	})
+
+	primary := descFile("main.proto", descTestPkg,
+		[]*descriptorpb.DescriptorProto{
+			descMessage("GetPairRequest", descStringField("id", 1)),
+			descMessage("Pair",
+				descMessageField("local_user", 1, descTestPkg+".User"),
+				descMessageField("other_user", 2, otherPkg+".User"),
+				descMessageField("error", 3, descTestPkg+".Error"),
+			),
+			descMessage("User", descStringField("name", 1)),
+			descMessage("Error", descStringField("code", 1)),
+		},
+		descService("PairService", nil,
+			withHTTPConfig(descMethod("GetPair", "GetPairRequest", "Pair"), &http.HttpConfig{
+				Path:   "/pairs/{id}",
+				Method: http.HttpMethod_HTTP_METHOD_GET,
+			}),
+		),
+	)
+	primary.Dependency = []string{"other.proto"}
+	return []*descriptorpb.FileDescriptorProto{other, primary}
+}
+
+func TestSchemaNaming(t *testing.T) {
+	tests := []struct {
+		naming   openapiv3.SchemaNaming
+		pairKey  string
+		wantKeys []string
+		wantRefs map[string]string // Pair property -> referenced schema
+	}{
+		{
+			naming:   openapiv3.SchemaNamingFQN,
+			pairKey:  "test.openapi.v1.Pair",
+			wantKeys: []string{"test.openapi.v1.User", "test.other.v1.User", "test.openapi.v1.Error"},
+			wantRefs: map[string]string{
+				"localUser": "#/components/schemas/test.openapi.v1.User",
+				"otherUser": "#/components/schemas/test.other.v1.User",
+			},
+		},
+		{
+			naming:   openapiv3.SchemaNamingPackagePrefixed,
+			pairKey:  "test_openapi_v1_Pair",
+			wantKeys: []string{"test_openapi_v1_User", "test_other_v1_User", "test_openapi_v1_Error"},
+			wantRefs: map[string]string{
+				"localUser": "#/components/schemas/test_openapi_v1_User",
+				"otherUser": "#/components/schemas/test_other_v1_User",
+			},
+		},
+		{
+			naming:   openapiv3.SchemaNamingShortDedup,
+			pairKey:  "Pair",
+			wantKeys: []string{"User", "test_other_v1_User", "test_openapi_v1_Error"},
+			wantRefs: map[string]string{
+				"localUser": "#/components/schemas/User",
+				"otherUser": "#/components/schemas/test_other_v1_User",
+				"error":     "#/components/schemas/test_openapi_v1_Error",
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(string(tt.naming), func(t *testing.T) {
+			plugin := buildDescPlugin(t, collidingUserFiles()...)
+			service := findDescService(t, plugin, "PairService")
+
+			gen := openapiv3.NewGenerator(openapiv3.FormatYAML)
+			gen.SetSchemaNaming(tt.naming)
+			gen.CollectReferencedMessages(service)
+			gen.ProcessService(service)
+
+			schemas := gen.Schemas()
+			for _, want := range append(tt.wantKeys, "Error") {
+				if _, ok := schemas.Get(want); !ok {
+					t.Errorf("schema %q missing", want)
+				}
+			}
+
+			pairProxy, ok := schemas.Get(tt.pairKey)
+			if !ok {
+				t.Fatalf("schema %q missing", tt.pairKey)
+			}
+			pair, err := pairProxy.BuildSchema()
+			if err != nil {
+				t.Fatalf("building %s schema: %v", tt.pairKey, err)
+			}
+			for property, wantRef := range tt.wantRefs {
+				prop, ok := pair.Properties.Get(property)
+				if !ok {
+					t.Fatalf("Pair has no %q property", property)
+				}
+				if got := prop.GetReference(); got != wantRef {
+					t.Errorf("Pair.%s $ref = %q, want %q", property, got, wantRef)
+				}
+			}
+		})
+	}
+}
+
+func TestParseSchemaNaming(t *testing.T) {
+	for _, valid := range []string{"short", "package_prefixed", "fqn", "short_dedup"} {
+		if _, err := openapiv3.ParseSchemaNaming(valid); err != nil {
+			t.Errorf("ParseSchemaNaming(%q) returned error: %v", valid, err)
+		}
+	}
+	if _, err := openapiv3.ParseSchemaNaming("camel"); err == nil {
+		t.Error("ParseSchemaNaming(\"camel\") should fail")
+	}
+}
@@ -178,3 +178,24 @@ func TestRender(t *testing.T) {
 		})
 	}
 }
+
+// Test RenderAs renders one processed document in either format.
+func TestRenderAs(t *testing.T) {
+	gen := openapiv3.NewGenerator(openapiv3.FormatYAML)
+
+	yamlData, err := gen.RenderAs(openapiv3.FormatYAML)
+	if err != nil {
+		t.Fatalf("RenderAs(yaml) error: %v", err)
+	}
+	if !strings.Contains(string(yamlData), "openapi: 3.1.0") {
+		t.Errorf("YAML output missing openapi version:\n%s", yamlData)
+	}
+
+	jsonData, err := gen.RenderAs(openapiv3.FormatJSON)
+	if err != nil {
+		t.Fatalf("RenderAs(json) error: %v", err)
+	}
+	if !strings.HasPrefix(string(jsonData), "{") || !strings.Contains(string(jsonData), `"openapi":"3.1.0"`) {
+		t.Errorf("unexpected JSON output:\n%s", jsonData)
+	}
+}
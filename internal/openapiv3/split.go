@@ -0,0 +1,160 @@
+package openapiv3
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+
+	yaml "go.yaml.in/yaml/v4"
+	k8syaml "sigs.k8s.io/yaml"
+)
+
+// ComponentsDir is the directory, relative to the main document, that RenderSplit
+// places schema files in.
+const ComponentsDir = "components"
+
+// schemaRefPrefix is the local reference prefix of component schemas.
+const schemaRefPrefix = "#/components/schemas/"
+
+// mappingPair is the number of content nodes per entry of a YAML mapping node (key, value).
+const mappingPair = 2
+
+// ComponentFile is a single component schema written to its own file by RenderSplit.
+type ComponentFile struct {
+	// Path is relative to the main document, e.g. "components/User.yaml".
+	Path    string
+	Content []byte
+}
+
+// namedSchema is a component schema detached from the document.
+type namedSchema struct {
+	name string
+	node *yaml.Node
+}
+
+// RenderSplit renders the document like RenderAs, but moves every component schema into
+// its own file under ComponentsDir. References to "#/components/schemas/{Name}" become
+// "components/{Name}.yaml" in the main document and "{Name}.yaml" between schema files
+// (".json" for FormatJSON).
+func (g *Generator) RenderSplit(format OutputFormat) ([]byte, []ComponentFile, error) {
+	data, err := yaml.Marshal(g.doc)
+	if err != nil {
+		return nil, nil, fmt.Errorf("failed to marshal to YAML: %w", err)
+	}
+	var root yaml.Node
+	if unmarshalErr := yaml.Unmarshal(data, &root); unmarshalErr != nil {
+		return nil, nil, fmt.Errorf("failed to parse rendered document: %w", unmarshalErr)
+	}
+	if len(root.Content) == 0 {
+		return nil, nil, errors.New("rendered document is empty")
+	}
+	doc := root.Content[0]
+	if g.hoistInlineSchemas {
+		hoistInlineSchemas(doc)
+	}
+
+	ext := "yaml"
+	if format == FormatJSON {
+		ext = "json"
+	}
+
+	schemas := detachSchemas(doc)
+	rewriteSchemaRefs(doc, ComponentsDir+"/", ext)
+	output, err := encodeNode(doc, format)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	files := make([]ComponentFile, 0, len(schemas))
+	for _, schema := range schemas {
+		rewriteSchemaRefs(schema.node, "", ext)
+		content, encodeErr := encodeNode(schema.node, format)
+		if encodeErr != nil {
+			return nil, nil, fmt.Errorf("schema %s: %w", schema.name, encodeErr)
+		}
+		files = append(files, ComponentFile{
+			Path:    ComponentsDir + "/" + schema.name + "." + ext,
+			Content: content,
+		})
+	}
+	return output, files, nil
+}
+
+// detachSchemas removes components.schemas from doc and returns its entries in order.
+// The components object itself is removed when nothing else is left in it.
+func detachSchemas(doc *yaml.Node) []namedSchema {
+	componentsIndex := mappingIndex(doc, "components")
+	if componentsIndex < 0 {
+		return nil
+	}
+	components := doc.Content[componentsIndex+1]
+	schemasIndex := mappingIndex(components, "schemas")
+	if schemasIndex < 0 {
+		return nil
+	}
+	schemasNode := components.Content[schemasIndex+1]
+
+	schemas := make([]namedSchema, 0, len(schemasNode.Content)/mappingPair)
+	for i := 0; i+1 < len(schemasNode.Content); i += mappingPair {
+		schemas = append(schemas, namedSchema{
+			name: schemasNode.Content[i].Value,
+			node: schemasNode.Content[i+1],
+		})
+	}
+
+	components.Content = slices.Delete(components.Content, schemasIndex, schemasIndex+mappingPair)
+	if len(components.Content) == 0 {
+		doc.Content = slices.Delete(doc.Content, componentsIndex, componentsIndex+mappingPair)
+	}
+	return schemas
+}
+
+// mappingIndex returns the index of key's key node in a mapping node, or -1.
+func mappingIndex(node *yaml.Node, key string) int {
+	if node.Kind != yaml.MappingNode {
+		return -1
+	}
+	for i := 0; i+1 < len(node.Content); i += mappingPair {
+		if node.Content[i].Value == key {
+			return i
+		}
+	}
+	return -1
+}
+
+// rewriteSchemaRefs points every component schema $ref under node at prefix+{Name}.ext.
+func rewriteSchemaRefs(node *yaml.Node, prefix, ext string) {
+	if node.Kind == yaml.MappingNode {
+		for i := 0; i+1 < len(node.Content); i += mappingPair {
+			key, value := node.Content[i], node.Content[i+1]
+			if key.Value != "$ref" || value.Kind != yaml.ScalarNode {
+				continue
+			}
+			name, ok := strings.CutPrefix(value.Value, schemaRefPrefix)
+			if ok && name != "" && !strings.Contains(name, "/") {
+				value.Value = prefix + name + "." + ext
+				value.Style = 0
+			}
+		}
+	}
+	for _, child := range node.Content {
+		rewriteSchemaRefs(child, prefix, ext)
+	}
+}
+
+// encodeNode renders a node in the given format.
+func encodeNode(node *yaml.Node, format OutputFormat) ([]byte, error) {
+	data, err := yaml.Marshal(node)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal to YAML: %w", err)
+	}
+	if format != FormatJSON {
+		return data, nil
+	}
+	jsonData, err := k8syaml.YAMLToJSON(data)
+	if err != nil {
+		return nil, fmt.Errorf("failed to convert YAML to JSON: %w", err)
+	}
+	return jsonData, nil
+}
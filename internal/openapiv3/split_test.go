@@ -0,0 +1,103 @@
+package openapiv3_test
+
+import (
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/types/descriptorpb"
+
+	"github.com/SebastienMelki/sebuf/http"
+	"github.com/SebastienMelki/sebuf/internal/openapiv3"
+)
+
+// addressServiceFile builds a service whose response message references another message.
+func addressServiceFile() *descriptorpb.FileDescriptorProto {
+	return descFile("address.proto", descTestPkg,
+		[]*descriptorpb.DescriptorProto{
+			descMessage("GetUserRequest", descStringField("id", 1)),
+			descMessage("Address", descStringField("city", 1)),
+			descMessage("User",
+				descStringField("id", 1),
+				descMessageField("address", 2, descTestPkg+".Address"),
+			),
+		},
+		descService("UserService", nil,
+			withHTTPConfig(descMethod("GetUser", "GetUserRequest", "User"), &http.HttpConfig{
+				Path:   "/users/{id}",
+				Method: http.HttpMethod_HTTP_METHOD_GET,
+			}),
+		),
+	)
+}
+
+func TestRenderSplit(t *testing.T) {
+	plugin := buildDescPlugin(t, addressServiceFile())
+	gen := generateService(t, plugin, "UserService")
+
+	tests := []struct {
+		format openapiv3.OutputFormat
+		ext    string
+	}{
+		{openapiv3.FormatYAML, "yaml"},
+		{openapiv3.FormatJSON, "json"},
+	}
+	for _, tt := range tests {
+		t.Run(string(tt.format), func(t *testing.T) {
+			output, files, err := gen.RenderSplit(tt.format)
+			if err != nil {
+				t.Fatalf("RenderSplit() error = %v", err)
+			}
+
+			document := string(output)
+			if strings.Contains(document, "#/components/schemas/") {
+				t.Errorf("main document still has local schema refs:\n%s", document)
+			}
+			if strings.Contains(document, "schemas") {
+				t.Errorf("main document still has components.schemas:\n%s", document)
+			}
+			if !strings.Contains(document, "components/User."+tt.ext) {
+				t.Errorf("main document does not reference components/User.%s:\n%s", tt.ext, document)
+			}
+
+			contents := make(map[string]string, len(files))
+			for _, file := range files {
+				contents[file.Path] = string(file.Content)
+			}
+			user, ok := contents["components/User."+tt.ext]
+			if !ok {
+				t.Fatalf("no components/User.%s in %v", tt.ext, contents)
+			}
+			if _, hasAddress := contents["components/Address."+tt.ext]; !hasAddress {
+				t.Errorf("no components/Address.%s in %v", tt.ext, contents)
+			}
+			if !strings.Contains(user, "Address."+tt.ext) || strings.Contains(user, "components/Address") {
+				t.Errorf("User schema should reference its sibling file Address.%s:\n%s", tt.ext, user)
+			}
+
+			problems, err := openapiv3.CheckDocument(output)
+			if err != nil || len(problems) > 0 {
+				t.Errorf("CheckDocument() = %v, %v; want no problems", problems, err)
+			}
+		})
+	}
+}
+
+func TestRenderSplitMatchesRenderAs(t *testing.T) {
+	plugin := buildDescPlugin(t, addressServiceFile())
+	gen := generateService(t, plugin, "UserService")
+
+	whole, err := gen.RenderAs(openapiv3.FormatYAML)
+	if err != nil {
+		t.Fatalf("RenderAs() error = %v", err)
+	}
+	if _, _, splitErr := gen.RenderSplit(openapiv3.FormatYAML); splitErr != nil {
+		t.Fatalf("RenderSplit() error = %v", splitErr)
+	}
+	again, err := gen.RenderAs(openapiv3.FormatYAML)
+	if err != nil {
+		t.Fatalf("RenderAs() error = %v", err)
+	}
+	if string(whole) != string(again) {
+		t.Error("RenderSplit must not modify the generator's document")
+	}
+}
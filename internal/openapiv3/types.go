@@ -1,14 +1,20 @@
 package openapiv3
 
 import (
+	"encoding/base64"
+	"encoding/hex"
 	"fmt"
+	"math"
+	"strconv"
 	"strings"
 
 	"github.com/pb33f/libopenapi/datamodel/high/base"
 	v3 "github.com/pb33f/libopenapi/datamodel/high/v3"
+	"github.com/pb33f/libopenapi/orderedmap"
 	yaml "go.yaml.in/yaml/v4"
 	"google.golang.org/protobuf/compiler/protogen"
 	"google.golang.org/protobuf/reflect/protoreflect"
+	"google.golang.org/protobuf/types/descriptorpb"
 
 	"github.com/SebastienMelki/sebuf/http"
 	"github.com/SebastienMelki/sebuf/internal/annotations"
@@ -26,8 +32,21 @@ const (
 	headerTypeDouble  = "double"
 )
 
-// convertField converts a protobuf field to an OpenAPI schema.
+// convertField converts a protobuf field to an OpenAPI schema, marking it deprecated
+// when the field carries `deprecated = true`.
 func (g *Generator) convertField(field *protogen.Field) *base.SchemaProxy {
+	schema := g.convertFieldType(field)
+	if field.Desc.HasDefault() {
+		schema = withSchemaDefault(schema, fieldDefaultNode(field))
+	}
+	if fieldOpts, ok := field.Desc.Options().(*descriptorpb.FieldOptions); ok && fieldOpts.GetDeprecated() {
+		return markSchemaDeprecated(schema)
+	}
+	return schema
+}
+
+// convertFieldType converts a protobuf field to an OpenAPI schema.
+func (g *Generator) convertFieldType(field *protogen.Field) *base.SchemaProxy {
 	// Handle repeated fields (arrays)
 	if field.Desc.IsList() {
 		itemSchema := g.convertScalarField(field)
@@ -101,6 +120,132 @@ func (g *Generator) makeNullableSchema(schemaProxy *base.SchemaProxy) *base.Sche
 	return base.CreateSchemaProxy(builtSchema)
 }
 
+// markSchemaDeprecated returns schemaProxy with `deprecated: true`. A $ref cannot be
+// annotated in place, so references are wrapped in a single-entry allOf.
+func markSchemaDeprecated(schemaProxy *base.SchemaProxy) *base.SchemaProxy {
+	deprecated := true
+	if schemaProxy.IsReference() {
+		return base.CreateSchemaProxy(&base.Schema{
+			AllOf:      []*base.SchemaProxy{schemaProxy},
+			Deprecated: &deprecated,
+		})
+	}
+
+	builtSchema, err := schemaProxy.BuildSchema()
+	if err != nil || builtSchema == nil {
+		return schemaProxy
+	}
+	builtSchema.Deprecated = &deprecated
+	return base.CreateSchemaProxy(builtSchema)
+}
+
+// withSchemaDefault returns schemaProxy with its `default` set to value. References
+// never carry an explicit default, so they are returned unchanged.
+func withSchemaDefault(schemaProxy *base.SchemaProxy, value *yaml.Node) *base.SchemaProxy {
+	if value == nil || schemaProxy.IsReference() {
+		return schemaProxy
+	}
+	builtSchema, err := schemaProxy.BuildSchema()
+	if err != nil || builtSchema == nil {
+		return schemaProxy
+	}
+	builtSchema.Default = value
+	return base.CreateSchemaProxy(builtSchema)
+}
+
+// fieldDefaultNode renders the explicit default of a proto2 (or editions) field, such as
+// `[default = 10]`, the way the field is encoded in JSON. It returns nil for fields
+// without a representable default.
+func fieldDefaultNode(field *protogen.Field) *yaml.Node {
+	value := field.Desc.Default()
+	switch field.Desc.Kind() {
+	case protoreflect.BoolKind:
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!bool", Value: strconv.FormatBool(value.Bool())}
+	case protoreflect.Int32Kind, protoreflect.Sint32Kind, protoreflect.Sfixed32Kind:
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!int", Value: strconv.FormatInt(value.Int(), 10)}
+	case protoreflect.Uint32Kind, protoreflect.Fixed32Kind:
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!int", Value: strconv.FormatUint(value.Uint(), 10)}
+	case protoreflect.Int64Kind, protoreflect.Sint64Kind, protoreflect.Sfixed64Kind:
+		return int64DefaultNode(field, strconv.FormatInt(value.Int(), 10))
+	case protoreflect.Uint64Kind, protoreflect.Fixed64Kind:
+		return int64DefaultNode(field, strconv.FormatUint(value.Uint(), 10))
+	case protoreflect.FloatKind:
+		return floatDefaultNode(value.Float(), 32) //nolint:mnd // float32 bit size
+	case protoreflect.DoubleKind:
+		return floatDefaultNode(value.Float(), 64) //nolint:mnd // float64 bit size
+	case protoreflect.StringKind:
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: value.String()}
+	case protoreflect.BytesKind:
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: encodeBytesDefault(field, value.Bytes())}
+	case protoreflect.EnumKind:
+		return enumDefaultNode(field, value.Enum())
+	case protoreflect.MessageKind, protoreflect.GroupKind:
+	}
+	return nil
+}
+
+// int64DefaultNode renders a 64-bit integer default as a JSON string, or as a number
+// under int64_encoding=NUMBER, matching the field's schema type.
+func int64DefaultNode(field *protogen.Field, digits string) *yaml.Node {
+	if annotations.IsInt64NumberEncoding(field) {
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!int", Value: digits}
+	}
+	return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: digits}
+}
+
+// floatDefaultNode renders a floating-point default. Infinities and NaN are written as
+// the strings protobuf JSON uses for them.
+func floatDefaultNode(value float64, bitSize int) *yaml.Node {
+	switch {
+	case math.IsNaN(value):
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: "NaN"}
+	case math.IsInf(value, 1):
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: "Infinity"}
+	case math.IsInf(value, -1):
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: "-Infinity"}
+	}
+	return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!float", Value: strconv.FormatFloat(value, 'g', -1, bitSize)}
+}
+
+// encodeBytesDefault encodes a bytes default with the field's bytes_encoding.
+func encodeBytesDefault(field *protogen.Field, value []byte) string {
+	//exhaustive:ignore -- UNSPECIFIED and BASE64 both use standard base64
+	switch annotations.GetBytesEncoding(field) {
+	case http.BytesEncoding_BYTES_ENCODING_HEX:
+		return hex.EncodeToString(value)
+	case http.BytesEncoding_BYTES_ENCODING_BASE64_RAW:
+		return base64.RawStdEncoding.EncodeToString(value)
+	case http.BytesEncoding_BYTES_ENCODING_BASE64URL:
+		return base64.URLEncoding.EncodeToString(value)
+	case http.BytesEncoding_BYTES_ENCODING_BASE64URL_RAW:
+		return base64.RawURLEncoding.EncodeToString(value)
+	default:
+		return base64.StdEncoding.EncodeToString(value)
+	}
+}
+
+// enumDefaultNode renders an enum default as its number under enum_encoding=NUMBER, and
+// otherwise as its enum_value mapping or proto name, matching convertEnumField.
+func enumDefaultNode(field *protogen.Field, number protoreflect.EnumNumber) *yaml.Node {
+	if annotations.GetEnumEncoding(field) == http.EnumEncoding_ENUM_ENCODING_NUMBER {
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!int", Value: strconv.Itoa(int(number))}
+	}
+	if field.Enum == nil {
+		return nil
+	}
+	for _, value := range field.Enum.Values {
+		if value.Desc.Number() != number {
+			continue
+		}
+		name := annotations.GetEnumValueMapping(value)
+		if name == "" {
+			name = string(value.Desc.Name())
+		}
+		return &yaml.Node{Kind: yaml.ScalarNode, Tag: "!!str", Value: name}
+	}
+	return nil
+}
+
 // convertScalarField handles scalar field types and message references.
 //
 //nolint:funlen // Large switch statement for all protobuf scalar types
@@ -186,6 +331,10 @@ func (g *Generator) convertScalarField(field *protogen.Field) *base.SchemaProxy
 		if annotations.IsTimestampField(field) {
 			return g.convertTimestampField(field, schema)
 		}
+		// Other well-known types serialize as their own JSON form, not as the message
+		if wkt, ok := annotations.GetWellKnownType(field.Message); ok {
+			return convertWellKnownField(field, wkt)
+		}
 		// Reference to another message
 		return base.CreateSchemaProxyRef(fmt.Sprintf("#/components/schemas/%s", g.getSchemaName(field.Message)))
 
@@ -254,7 +403,7 @@ func (g *Generator) convertEnumField(field *protogen.Field) *base.SchemaProxy {
 			Type: []string{headerTypeInteger},
 			Enum: make([]*yaml.Node, 0, len(field.Enum.Values)),
 		}
-		for _, value := range field.Enum.Values {
+		for _, value := range distinctEnumValues(field.Enum) {
 			schema.Enum = append(schema.Enum, &yaml.Node{
 				Kind:  yaml.ScalarNode,
 				Tag:   "!!int",
@@ -265,6 +414,9 @@ func (g *Generator) convertEnumField(field *protogen.Field) *base.SchemaProxy {
 		if field.Enum.Comments.Leading != "" {
 			schema.Description = strings.TrimSpace(string(field.Enum.Comments.Leading))
 		}
+		if g.enumDetails {
+			addEnumExtensions(schema, field.Enum, true)
+		}
 		return base.CreateSchemaProxy(schema)
 	}
 
@@ -292,9 +444,106 @@ func (g *Generator) convertEnumField(field *protogen.Field) *base.SchemaProxy {
 		schema.Description = strings.TrimSpace(string(field.Enum.Comments.Leading))
 	}
 
+	if g.enumDetails {
+		addEnumExtensions(schema, field.Enum, false)
+		if !hasEnumValueMappings(field.Enum) {
+			return buildStringOrNumberEnumSchema(schema, field.Enum)
+		}
+	}
+
 	return base.CreateSchemaProxy(schema)
 }
 
+// hasEnumValueMappings reports whether any value of enum carries an enum_value annotation.
+func hasEnumValueMappings(enum *protogen.Enum) bool {
+	for _, value := range enum.Values {
+		if annotations.GetEnumValueMapping(value) != "" {
+			return true
+		}
+	}
+	return false
+}
+
+// buildStringOrNumberEnumSchema documents that a string-encoded enum also accepts its
+// numeric values, as protojson does when parsing: the name schema is paired with an
+// integer alternative in a oneOf. The description stays on the outer schema.
+func buildStringOrNumberEnumSchema(nameSchema *base.Schema, enum *protogen.Enum) *base.SchemaProxy {
+	numberSchema := &base.Schema{
+		Type: []string{headerTypeInteger},
+		Enum: make([]*yaml.Node, 0, len(enum.Values)),
+	}
+	for _, value := range distinctEnumValues(enum) {
+		numberSchema.Enum = append(numberSchema.Enum, &yaml.Node{
+			Kind:  yaml.ScalarNode,
+			Tag:   "!!int",
+			Value: fmt.Sprintf("%d", value.Desc.Number()),
+		})
+	}
+	addEnumExtensions(numberSchema, enum, true)
+
+	description := nameSchema.Description
+	nameSchema.Description = ""
+	return base.CreateSchemaProxy(&base.Schema{
+		Description: description,
+		OneOf:       []*base.SchemaProxy{base.CreateSchemaProxy(nameSchema), base.CreateSchemaProxy(numberSchema)},
+	})
+}
+
+// addEnumExtensions attaches x-enum-descriptions, built from the comments of each enum
+// value, and for numeric enums x-enum-varnames with the value names. Both follow the
+// order of schema.Enum.
+func addEnumExtensions(schema *base.Schema, enum *protogen.Enum, numeric bool) {
+	varNames := &yaml.Node{Kind: yaml.SequenceNode}
+	descriptions := &yaml.Node{Kind: yaml.SequenceNode}
+	hasDescriptions := false
+	values := enum.Values
+	if numeric {
+		values = distinctEnumValues(enum)
+	}
+	for _, value := range values {
+		varNames.Content = append(varNames.Content, &yaml.Node{Kind: yaml.ScalarNode, Value: string(value.Desc.Name())})
+		description := strings.TrimSpace(string(value.Comments.Leading))
+		if description == "" {
+			description = strings.TrimSpace(string(value.Comments.Trailing))
+		}
+		hasDescriptions = hasDescriptions || description != ""
+		descriptions.Content = append(descriptions.Content, &yaml.Node{
+			Kind:  yaml.ScalarNode,
+			Tag:   "!!str",
+			Value: description,
+		})
+	}
+
+	if !numeric && !hasDescriptions {
+		return
+	}
+	if schema.Extensions == nil {
+		schema.Extensions = orderedmap.New[string, *yaml.Node]()
+	}
+	if numeric {
+		schema.Extensions.Set("x-enum-varnames", varNames)
+	}
+	if hasDescriptions {
+		schema.Extensions.Set("x-enum-descriptions", descriptions)
+	}
+}
+
+// distinctEnumValues returns the values of enum with one value per number: the first
+// declared, which is the name protojson writes. With allow_alias several names share a
+// number, and a numeric enum list must not repeat it. Name lists keep every alias, since
+// protojson accepts each of them.
+func distinctEnumValues(enum *protogen.Enum) []*protogen.EnumValue {
+	values := make([]*protogen.EnumValue, 0, len(enum.Values))
+	seen := make(map[protoreflect.EnumNumber]bool, len(enum.Values))
+	for _, value := range enum.Values {
+		if !seen[value.Desc.Number()] {
+			seen[value.Desc.Number()] = true
+			values = append(values, value)
+		}
+	}
+	return values
+}
+
 // convertMapField converts a protobuf map field to an OpenAPI schema.
 func (g *Generator) convertMapField(field *protogen.Field) *base.SchemaProxy {
 	schema := &base.Schema{
@@ -467,6 +716,40 @@ func (g *Generator) convertTimestampField(field *protogen.Field, schema *base.Sc
 	return base.CreateSchemaProxy(schema)
 }
 
+// convertWellKnownField creates an OpenAPI schema for a google.protobuf well-known type
+// field from its ProtoJSON form, honoring int64_encoding on the 64-bit wrappers.
+func convertWellKnownField(field *protogen.Field, wkt annotations.WellKnownType) *base.SchemaProxy {
+	schema := wellKnownSchema(wkt)
+	numberEncoded := wkt.Int64 && annotations.IsInt64NumberEncoding(field)
+	if numberEncoded {
+		schema.Type = []string{headerTypeInteger}
+	}
+
+	// Override description with field comments if present
+	if field.Comments.Leading != "" {
+		schema.Description = strings.TrimSpace(string(field.Comments.Leading))
+	}
+	if numberEncoded {
+		appendInt64PrecisionWarning(schema)
+	}
+
+	return base.CreateSchemaProxy(schema)
+}
+
+// wellKnownSchema returns the schema of a well-known type's ProtoJSON form.
+// google.protobuf.Value gets no type, so any JSON value is accepted.
+func wellKnownSchema(wkt annotations.WellKnownType) *base.Schema {
+	schema := &base.Schema{
+		Format:      wkt.Format,
+		Pattern:     wkt.Pattern,
+		Description: wkt.Description,
+	}
+	if wkt.JSONType != "" {
+		schema.Type = []string{wkt.JSONType}
+	}
+	return schema
+}
+
 // int64PrecisionWarning is the warning message for NUMBER-encoded int64/uint64 fields.
 const int64PrecisionWarning = "Warning: Values > 2^53 may lose precision in JavaScript"
 
@@ -0,0 +1,164 @@
+// Package plugincli handles the command line of the sebuf protoc plugins.
+//
+// The plugins are run by protoc or buf, which write a CodeGeneratorRequest to stdin and
+// pass no arguments. People also run them by hand, to check an installation or by
+// mistake; HandleArgs answers --version and --help and explains how the plugin is meant
+// to be run instead of leaving it waiting on a terminal.
+//
+// Every plugin main calls Run in place of protogen.Options.Run, so they all handle their
+// command line and stdin the same way.
+package plugincli
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"runtime/debug"
+	"strings"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/pluginpb"
+)
+
+// Exit codes returned by HandleArgs.
+const (
+	ExitOK    = 0
+	ExitUsage = 2
+)
+
+// develVersion is the version reported by a binary built from a local checkout.
+const develVersion = "(devel)"
+
+// Version returns the sebuf version the plugin was built from: the module version for a
+// binary installed with go install, or "(devel)" for a local build.
+func Version() string {
+	info, ok := debug.ReadBuildInfo()
+	if !ok || info.Main.Version == "" {
+		return develVersion
+	}
+	return info.Main.Version
+}
+
+// Run is the main function of the plugin called name. It handles the command line with
+// HandleArgs, then serves the request on stdin with Serve, writing the response to stdout.
+// It exits nonzero only for a usage error, or when the request cannot be read or the
+// response cannot be written.
+func Run(name string, opts protogen.Options, f func(*protogen.Plugin) error) {
+	if exit, code := HandleArgs(name, os.Args[1:], os.Stdin, os.Stdout, os.Stderr); exit {
+		os.Exit(code)
+	}
+	if err := Serve(name, opts, os.Stdin, os.Stdout, f); err != nil {
+		fmt.Fprintf(os.Stderr, "%s: %v\n", name, err)
+		os.Exit(1)
+	}
+}
+
+// Serve reads the request of the plugin called name from r with ReadRequest, builds the
+// plugin with opts, calls f on it and writes the response to w. Errors from opts (such as
+// an invalid parameter) and from f are reported in CodeGeneratorResponse.error, which
+// protoc prints after the plugin's --*_out flag; the returned error is for I/O only.
+func Serve(name string, opts protogen.Options, r io.Reader, w io.Writer, f func(*protogen.Plugin) error) error {
+	req, err := ReadRequest(name, r)
+	if err != nil {
+		return err
+	}
+
+	var resp *pluginpb.CodeGeneratorResponse
+	if plugin, newErr := opts.New(req); newErr != nil {
+		resp = &pluginpb.CodeGeneratorResponse{Error: proto.String(newErr.Error())}
+	} else {
+		if genErr := f(plugin); genErr != nil {
+			plugin.Error(genErr)
+		}
+		resp = plugin.Response()
+	}
+
+	out, err := proto.Marshal(resp)
+	if err != nil {
+		return fmt.Errorf("failed to marshal response: %w", err)
+	}
+	if _, err = w.Write(out); err != nil {
+		return fmt.Errorf("failed to write response: %w", err)
+	}
+	return nil
+}
+
+// HandleArgs handles the command line of the plugin called name. --version and --help
+// print to stdout; any other argument, or no argument with a terminal on stdin, prints
+// usage to stderr. It returns whether the program should exit, and with which code.
+// When it returns false the plugin should go on to read its request from stdin.
+func HandleArgs(name string, args []string, stdin *os.File, stdout, stderr io.Writer) (bool, int) {
+	if len(args) == 0 {
+		if isTerminal(stdin) {
+			fmt.Fprintf(stderr, "%s: no CodeGeneratorRequest on stdin\n\n", name)
+			printUsage(stderr, name)
+			return true, ExitUsage
+		}
+		return false, ExitOK
+	}
+
+	switch args[0] {
+	case "--version", "-version":
+		fmt.Fprintf(stdout, "%s %s\n", name, Version())
+		return true, ExitOK
+	case "--help", "-help", "-h":
+		printUsage(stdout, name)
+		return true, ExitOK
+	default:
+		fmt.Fprintf(stderr, "%s: unknown argument %q\n\n", name, args[0])
+		printUsage(stderr, name)
+		return true, ExitUsage
+	}
+}
+
+// ReadRequest reads the CodeGeneratorRequest of the plugin called name from r. Its
+// errors say how the plugin is meant to be run, since an empty or unparsable stdin
+// usually means the binary was run directly.
+func ReadRequest(name string, r io.Reader) (*pluginpb.CodeGeneratorRequest, error) {
+	input, err := io.ReadAll(r)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read request: %w", err)
+	}
+	if len(input) == 0 {
+		return nil, fmt.Errorf("no CodeGeneratorRequest on stdin; %s is run by protoc or buf, see %s --help",
+			name, name)
+	}
+
+	var req pluginpb.CodeGeneratorRequest
+	if unmarshalErr := proto.Unmarshal(input, &req); unmarshalErr != nil {
+		return nil, fmt.Errorf("stdin is not a CodeGeneratorRequest (%w); %s is run by protoc or buf, see %s --help",
+			unmarshalErr, name, name)
+	}
+	return &req, nil
+}
+
+// printUsage writes how to run the plugin called name to w.
+func printUsage(w io.Writer, name string) {
+	fmt.Fprintf(w, `Usage: %[1]s [--version | --help]
+
+%[1]s is a protoc plugin. protoc or buf runs it with a CodeGeneratorRequest on
+stdin and reads the generated files from stdout; it is not meant to be run directly.
+
+  protoc --%[2]s_out=OUT_DIR [--%[2]s_opt=OPTIONS] FILE.proto
+
+With buf, list the plugin in buf.gen.yaml and run buf generate.
+
+Options:
+  --version  print the version and exit
+  --help     print this help and exit
+`, name, strings.TrimPrefix(name, "protoc-gen-"))
+}
+
+// isTerminal reports whether f is a character device, which for stdin means a terminal
+// rather than the pipe protoc writes to.
+func isTerminal(f *os.File) bool {
+	if f == nil {
+		return false
+	}
+	info, err := f.Stat()
+	if err != nil {
+		return false
+	}
+	return info.Mode()&os.ModeCharDevice != 0
+}
@@ -0,0 +1,164 @@
+package plugincli_test
+
+import (
+	"bytes"
+	"errors"
+	"io"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"google.golang.org/protobuf/compiler/protogen"
+	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/types/pluginpb"
+
+	"github.com/SebastienMelki/sebuf/internal/plugincli"
+)
+
+const testPlugin = "protoc-gen-test"
+
+// pipeStdin returns a regular file standing in for the stdin protoc writes to.
+func pipeStdin(t *testing.T) *os.File {
+	t.Helper()
+	f, err := os.Create(filepath.Join(t.TempDir(), "stdin"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	t.Cleanup(func() { f.Close() })
+	return f
+}
+
+func TestHandleArgs(t *testing.T) {
+	tests := []struct {
+		name       string
+		args       []string
+		wantExit   bool
+		wantCode   int
+		wantStdout string
+		wantStderr string
+	}{
+		{name: "run by protoc", args: nil},
+		{
+			name:       "version",
+			args:       []string{"--version"},
+			wantExit:   true,
+			wantCode:   plugincli.ExitOK,
+			wantStdout: testPlugin + " " + plugincli.Version(),
+		},
+		{
+			name:       "help",
+			args:       []string{"--help"},
+			wantExit:   true,
+			wantCode:   plugincli.ExitOK,
+			wantStdout: "protoc --test_out=OUT_DIR",
+		},
+		{
+			name:       "unknown argument",
+			args:       []string{"--bogus"},
+			wantExit:   true,
+			wantCode:   plugincli.ExitUsage,
+			wantStderr: `unknown argument "--bogus"`,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			var stdout, stderr bytes.Buffer
+			exit, code := plugincli.HandleArgs(testPlugin, tt.args, pipeStdin(t), &stdout, &stderr)
+			if exit != tt.wantExit || code != tt.wantCode {
+				t.Errorf("HandleArgs() = %v, %d, want %v, %d", exit, code, tt.wantExit, tt.wantCode)
+			}
+			if !strings.Contains(stdout.String(), tt.wantStdout) || (tt.wantStdout == "" && stdout.Len() > 0) {
+				t.Errorf("stdout = %q, want it to contain %q", stdout.String(), tt.wantStdout)
+			}
+			if !strings.Contains(stderr.String(), tt.wantStderr) || (tt.wantStderr == "" && stderr.Len() > 0) {
+				t.Errorf("stderr = %q, want it to contain %q", stderr.String(), tt.wantStderr)
+			}
+		})
+	}
+}
+
+func TestReadRequest(t *testing.T) {
+	data, err := proto.Marshal(&pluginpb.CodeGeneratorRequest{Parameter: proto.String("format=json")})
+	if err != nil {
+		t.Fatal(err)
+	}
+	req, err := plugincli.ReadRequest(testPlugin, bytes.NewReader(data))
+	if err != nil {
+		t.Fatalf("ReadRequest() error = %v", err)
+	}
+	if req.GetParameter() != "format=json" {
+		t.Errorf("parameter = %q, want format=json", req.GetParameter())
+	}
+
+	for input, want := range map[string]string{
+		"":            "no CodeGeneratorRequest on stdin",
+		"hello world": "stdin is not a CodeGeneratorRequest",
+	} {
+		if _, readErr := plugincli.ReadRequest(testPlugin, strings.NewReader(input)); readErr == nil ||
+			!strings.Contains(readErr.Error(), want) {
+			t.Errorf("ReadRequest(%q) error = %v, want it to contain %q", input, readErr, want)
+		}
+	}
+}
+
+func TestServe(t *testing.T) {
+	data, err := proto.Marshal(&pluginpb.CodeGeneratorRequest{Parameter: proto.String("flag=on")})
+	if err != nil {
+		t.Fatal(err)
+	}
+	serve := func(t *testing.T, opts protogen.Options, f func(*protogen.Plugin) error) *pluginpb.CodeGeneratorResponse {
+		t.Helper()
+		var out bytes.Buffer
+		if serveErr := plugincli.Serve(testPlugin, opts, bytes.NewReader(data), &out, f); serveErr != nil {
+			t.Fatalf("Serve() error = %v", serveErr)
+		}
+		var resp pluginpb.CodeGeneratorResponse
+		if unmarshalErr := proto.Unmarshal(out.Bytes(), &resp); unmarshalErr != nil {
+			t.Fatal(unmarshalErr)
+		}
+		return &resp
+	}
+
+	t.Run("runs the plugin", func(t *testing.T) {
+		var parameter string
+		resp := serve(t, protogen.Options{}, func(plugin *protogen.Plugin) error {
+			parameter = plugin.Request.GetParameter()
+			return nil
+		})
+		if parameter != "flag=on" || resp.Error != nil {
+			t.Errorf("parameter = %q, response error = %v", parameter, resp.Error)
+		}
+	})
+
+	t.Run("generation error goes in the response", func(t *testing.T) {
+		resp := serve(t, protogen.Options{}, func(*protogen.Plugin) error {
+			return errors.New("boom")
+		})
+		if resp.GetError() != "boom" {
+			t.Errorf("response error = %q, want boom", resp.GetError())
+		}
+	})
+
+	t.Run("parameter error goes in the response", func(t *testing.T) {
+		opts := protogen.Options{ParamFunc: func(name, _ string) error {
+			return errors.New("bad parameter " + name)
+		}}
+		resp := serve(t, opts, func(*protogen.Plugin) error {
+			t.Error("the plugin ran despite a parameter error")
+			return nil
+		})
+		if resp.GetError() != "bad parameter flag" {
+			t.Errorf("response error = %q, want bad parameter flag", resp.GetError())
+		}
+	})
+
+	t.Run("empty stdin", func(t *testing.T) {
+		serveErr := plugincli.Serve(testPlugin, protogen.Options{}, strings.NewReader(""), io.Discard,
+			func(*protogen.Plugin) error { return nil })
+		if serveErr == nil || !strings.Contains(serveErr.Error(), "no CodeGeneratorRequest on stdin") {
+			t.Errorf("Serve() error = %v, want it to report the empty stdin", serveErr)
+		}
+	})
+}
@@ -112,7 +112,7 @@ func writeRPCMethod(p printer, service *protogen.Service, method *protogen.Metho
 		return
 	}
 
-	pyMethodName := snakeCase(string(method.Desc.Name()))
+	pyMethodName := pythonMethodName(method)
 	inputType := pythonTypeName(method.Input)
 	outputType := resolveOutputType(method)
 
@@ -137,7 +137,7 @@ func writeRPCMethod(p printer, service *protogen.Service, method *protogen.Metho
 }
 
 func writeSSEMethodStub(p printer, method *protogen.Method, serviceName string, cfg *methodConfig) {
-	pyMethodName := snakeCase(string(method.Desc.Name()))
+	pyMethodName := pythonMethodName(method)
 	inputType := pythonTypeName(method.Input)
 	outputType := resolveOutputType(method)
 
@@ -157,7 +157,7 @@ func writeSSEMethodStub(p printer, method *protogen.Method, serviceName string,
 func writePathBuilding(p printer, cfg *methodConfig) {
 	p(`        path = "%s"`, cfg.fullPath)
 	for _, param := range cfg.pathParams {
-		pyName := snakeCase(param)
+		pyName := escapePyKeyword(snakeCase(param))
 		p(`        path = path.replace("{%s}", urllib.parse.quote(str(req.%s), safe=""))`, param, pyName)
 	}
 }
@@ -329,6 +329,12 @@ func buildMethodConfig(service *protogen.Service, method *protogen.Method) *meth
 	}
 }
 
+// pythonMethodName returns the client method name for an RPC: its snake_case name,
+// escaped when it is a Python keyword (e.g. an Import RPC becomes import_).
+func pythonMethodName(method *protogen.Method) string {
+	return escapePyKeyword(snakeCase(string(method.Desc.Name())))
+}
+
 // snakeCaseExtraCapacity is the expected number of underscores inserted when
 // converting CamelCase to snake_case. Pre-sizing the output avoids realloc on
 // most identifiers.
@@ -1,6 +1,10 @@
 package pyclientgen
 
-import "testing"
+import (
+	"fmt"
+	"strings"
+	"testing"
+)
 
 func TestSnakeCase(t *testing.T) {
 	tests := []struct {
@@ -153,3 +157,25 @@ func TestStripOptional(t *testing.T) {
 		})
 	}
 }
+
+func TestWritePathBuilding(t *testing.T) {
+	var lines []string
+	p := func(format string, args ...interface{}) {
+		lines = append(lines, fmt.Sprintf(format, args...))
+	}
+	writePathBuilding(p, &methodConfig{
+		fullPath:   "/flights/{from}/{flight_id}",
+		pathParams: []string{"from", "flight_id"},
+	})
+
+	// The dataclass attribute of a keyword-named field is escaped, so the path
+	// substitution must read the escaped name.
+	want := []string{
+		`        path = "/flights/{from}/{flight_id}"`,
+		`        path = path.replace("{from}", urllib.parse.quote(str(req.from_), safe=""))`,
+		`        path = path.replace("{flight_id}", urllib.parse.quote(str(req.flight_id), safe=""))`,
+	}
+	if strings.Join(lines, "\n") != strings.Join(want, "\n") {
+		t.Errorf("writePathBuilding() =\n%s\nwant\n%s", strings.Join(lines, "\n"), strings.Join(want, "\n"))
+	}
+}
@@ -174,7 +174,8 @@ func (ms *MessageSet) AddMessage(msg *protogen.Message) {
 	if msg.Desc.IsMapEntry() {
 		// Still recurse into value type if it's a message
 		for _, field := range msg.Fields {
-			if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil {
+			if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil &&
+				!annotations.IsWellKnownField(field) {
 				ms.AddMessage(field.Message)
 			}
 			if field.Desc.Kind() == protoreflect.EnumKind && field.Enum != nil {
@@ -189,7 +190,8 @@ func (ms *MessageSet) AddMessage(msg *protogen.Message) {
 
 	// Recurse into all fields
 	for _, field := range msg.Fields {
-		if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil {
+		if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil &&
+			!annotations.IsWellKnownField(field) {
 			ms.AddMessage(field.Message)
 		}
 		if field.Desc.Kind() == protoreflect.EnumKind && field.Enum != nil {
@@ -312,6 +314,11 @@ func TSFieldTypeCtx(ctx *EmitContext, field *protogen.Field) string {
 		return TSTimestampType(field)
 	}
 
+	// Other well-known types also serialize as their own JSON form
+	if tsType, ok := TSWellKnownType(field); ok {
+		return tsType
+	}
+
 	// Handle message fields
 	if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil {
 		return ctx.RefMessage(field.Message)
@@ -342,6 +349,9 @@ func TSElementTypeCtx(ctx *EmitContext, field *protogen.Field) string {
 	if annotations.IsTimestampField(field) {
 		return TSTimestampType(field)
 	}
+	if tsType, ok := TSWellKnownType(field); ok {
+		return tsType
+	}
 	if field.Desc.Kind() == protoreflect.MessageKind && field.Message != nil {
 		return ctx.RefMessage(field.Message)
 	}
@@ -876,8 +886,8 @@ func GenerateFlattenedFieldsCtx(ctx *EmitContext, p Printer, childMsg *protogen.
 
 // IsOptionalField returns true if the field should be optional in TypeScript.
 func IsOptionalField(field *protogen.Field) bool {
-	// Explicit proto3 optional
-	if field.Desc.HasOptionalKeyword() {
+	// Explicit proto3 optional, or explicit presence in an edition file
+	if annotations.HasExplicitPresence(field) {
 		return true
 	}
 	// Message-typed fields are nullable in proto3
@@ -887,6 +897,32 @@ func IsOptionalField(field *protogen.Field) bool {
 	return false
 }
 
+// TSWellKnownType returns the TypeScript type of the JSON form of a google.protobuf
+// well-known type field, such as string for a Duration or number for an Int32Value.
+// It returns false for any other field.
+func TSWellKnownType(field *protogen.Field) (string, bool) {
+	wkt, ok := annotations.GetWellKnownType(field.Message)
+	if !ok {
+		return "", false
+	}
+	switch wkt.JSONType {
+	case annotations.JSONTypeString:
+		if wkt.Int64 && annotations.IsInt64NumberEncoding(field) {
+			return TSNumber, true
+		}
+		return TSString, true
+	case annotations.JSONTypeInteger, annotations.JSONTypeNumber:
+		return TSNumber, true
+	case annotations.JSONTypeBoolean:
+		return TSBoolean, true
+	case annotations.JSONTypeObject:
+		return "Record<string, unknown>", true
+	case annotations.JSONTypeArray:
+		return "unknown[]", true
+	}
+	return "unknown", true
+}
+
 // TSTimestampType returns the TypeScript type for a google.protobuf.Timestamp field
 // based on its timestamp_format annotation.
 // UNIX_SECONDS and UNIX_MILLIS serialize as integers -> number
@@ -9,8 +9,12 @@ import (
 
 	"google.golang.org/protobuf/compiler/protogen"
 	"google.golang.org/protobuf/proto"
+	"google.golang.org/protobuf/reflect/protodesc"
 	"google.golang.org/protobuf/reflect/protoreflect"
 	"google.golang.org/protobuf/types/descriptorpb"
+	"google.golang.org/protobuf/types/known/durationpb"
+	"google.golang.org/protobuf/types/known/structpb"
+	"google.golang.org/protobuf/types/known/wrapperspb"
 	"google.golang.org/protobuf/types/pluginpb"
 
 	"github.com/SebastienMelki/sebuf/internal/annotations"
@@ -557,3 +561,69 @@ export type PlainEvent = PlainEventBase & PlainEventContent;
 		}
 	})
 }
+
+func TestTSWellKnownType(t *testing.T) {
+	wkt := func(name string, number int32, fullName string) *descriptorpb.FieldDescriptorProto {
+		f := msgFieldProto(name, number, descriptorpb.FieldDescriptorProto_TYPE_MESSAGE)
+		f.TypeName = proto.String("." + fullName)
+		return f
+	}
+	tags := wkt("tags", 5, "google.protobuf.StringValue")
+	tags.Label = descriptorpb.FieldDescriptorProto_LABEL_REPEATED.Enum()
+	fd := &descriptorpb.FileDescriptorProto{
+		Name:    proto.String("wkt.proto"),
+		Package: proto.String(testProtoPkg),
+		Syntax:  proto.String("proto3"),
+		Dependency: []string{
+			"google/protobuf/duration.proto",
+			"google/protobuf/struct.proto",
+			"google/protobuf/wrappers.proto",
+		},
+		Options: &descriptorpb.FileOptions{
+			GoPackage: proto.String("github.com/SebastienMelki/sebuf/internal/tscommon/oneofv1"),
+		},
+		MessageType: []*descriptorpb.DescriptorProto{{
+			Name: proto.String("Job"),
+			Field: []*descriptorpb.FieldDescriptorProto{
+				wkt("timeout", 1, "google.protobuf.Duration"),
+				wkt("attempts", 2, "google.protobuf.Int32Value"),
+				wkt("metadata", 3, "google.protobuf.Struct"),
+				wkt("payload", 4, "google.protobuf.Value"),
+				tags,
+			},
+		}},
+	}
+	plugin, err := protogen.Options{}.New(&pluginpb.CodeGeneratorRequest{
+		FileToGenerate: []string{fd.GetName()},
+		ProtoFile: []*descriptorpb.FileDescriptorProto{
+			protodesc.ToFileDescriptorProto(durationpb.File_google_protobuf_duration_proto),
+			protodesc.ToFileDescriptorProto(structpb.File_google_protobuf_struct_proto),
+			protodesc.ToFileDescriptorProto(wrapperspb.File_google_protobuf_wrappers_proto),
+			fd,
+		},
+	})
+	if err != nil {
+		t.Fatalf("protogen.Options{}.New: %v", err)
+	}
+	job := findMessage(t, plugin, "Job")
+
+	want := map[string]string{
+		"timeout":  "string",
+		"attempts": "number",
+		"metadata": "Record<string, unknown>",
+		"payload":  "unknown",
+		"tags":     "string[]",
+	}
+	for _, field := range job.Fields {
+		name := string(field.Desc.Name())
+		if got := TSFieldType(field); got != want[name] {
+			t.Errorf("TSFieldType(%s) = %q, want %q", name, got, want[name])
+		}
+	}
+
+	ms := NewMessageSet()
+	ms.AddMessage(job)
+	if messages := ms.OrderedMessages(); len(messages) != 1 {
+		t.Errorf("OrderedMessages() has %d messages, want only Job: well-known types need no interface", len(messages))
+	}
+}
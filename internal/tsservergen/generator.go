@@ -3,6 +3,7 @@ package tsservergen
 import (
 	"fmt"
 	"net/http"
+	"regexp"
 	"strings"
 
 	"google.golang.org/protobuf/compiler/protogen"
@@ -635,6 +636,12 @@ func (g *Generator) generatePathParamExtraction(p tscommon.Printer, cfg *rpcRout
 				p("          pathParams[\"%s\"] = decodeURIComponent(pathSegments[%d] ?? \"\");", param, i)
 				break
 			}
+			// A custom verb follows the last path variable ("{id}:activate"): strip it.
+			if verb, ok := strings.CutPrefix(seg, paramPlaceholder+":"); ok {
+				p("          pathParams[\"%s\"] = decodeURIComponent(pathSegments[%d] ?? \"\").replace(/:%s$/, \"\");",
+					param, i, regexp.QuoteMeta(verb))
+				break
+			}
 		}
 	}
 	p("")
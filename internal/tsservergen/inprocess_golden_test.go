@@ -128,6 +128,36 @@ func TestTSServerGenInProcessReservedName(t *testing.T) {
 	}
 }
 
+// TestTSServerGenInProcessCustomVerb asserts that a path variable followed by a
+// custom verb ("{id}:activate") is extracted without the verb.
+func TestTSServerGenInProcessCustomVerb(t *testing.T) {
+	if _, err := exec.LookPath("protoc"); err != nil {
+		t.Skip("protoc not found, skipping in-process custom verb test")
+	}
+
+	baseDir, err := os.Getwd()
+	if err != nil {
+		t.Fatalf("Failed to get working directory: %v", err)
+	}
+	projectRoot := filepath.Join(baseDir, "..", "..")
+	protoDir := filepath.Join(baseDir, "testdata", "proto")
+
+	plugin := buildInProcessPlugin(t, protoDir, projectRoot, []string{"custom_verb.proto"})
+	if genErr := New(plugin).Generate(); genErr != nil {
+		t.Fatalf("Generate() failed: %v", genErr)
+	}
+
+	content := generatedFileContent(t, plugin, "custom_verb_server.ts")
+	for _, want := range []string{
+		`path: "/v1/users/{id}:activate",`,
+		`pathParams["id"] = decodeURIComponent(pathSegments[3] ?? "").replace(/:activate$/, "");`,
+	} {
+		if !strings.Contains(content, want) {
+			t.Errorf("custom_verb_server.ts missing %q\n---\n%s", want, content)
+		}
+	}
+}
+
 // generatedFileContent returns the content of the named file from the plugin
 // response, failing the test if it was not emitted.
 func generatedFileContent(t *testing.T, plugin *protogen.Plugin, name string) string {